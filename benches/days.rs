@@ -0,0 +1,43 @@
+//! Benches the parser and all parts of every day registered in `src/days` against its input.
+//!
+//! Run with `cargo bench`, or `cargo bench -- 2023_day01` to bench a single day. Reports are written to
+//! `target/criterion`, `cargo time --criterion` imports them into the readme.
+use advent_of_code::template::bench_reports::{group_name, part_name};
+use advent_of_code::template::{
+    registry, runner::is_normalization_enabled, set_year, try_read_input,
+};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn bench_days(c: &mut Criterion) {
+    for solution in registry::all() {
+        let (year, day) = (solution.year, solution.day);
+
+        // inputs are read from the data of the year the solution belongs to.
+        set_year(year);
+        let input = match try_read_input(day, is_normalization_enabled()) {
+            Ok((input, _)) => input,
+            Err(e) => {
+                eprintln!("Skipping day {day} of {year}: {e}");
+                continue;
+            }
+        };
+
+        let mut group = c.benchmark_group(group_name(year, day));
+
+        if let Some(parse) = solution.parse {
+            group.bench_function("parse", |b| b.iter(|| parse(black_box(&input))));
+        }
+
+        for part in solution.parts {
+            let run = part.run;
+            group.bench_function(part_name(part.number), |b| {
+                b.iter(|| run(black_box(&input)));
+            });
+        }
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
@@ -0,0 +1,75 @@
+//! Exposes the paths of puzzle inputs to the `solution!` macro when the `embed-inputs` feature
+//! is enabled, so that inputs can be embedded into solution bins via `include_str!`.
+//! The year of every solution bin is exposed as well, to name it in the error for missing inputs.
+use std::{env, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    if env::var_os("CARGO_FEATURE_EMBED_INPUTS").is_none() {
+        return;
+    }
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    // re-run when a bin is scaffolded.
+    println!("cargo:rerun-if-changed={}", bin_dir.display());
+
+    // bins are named after their year and day, e.g. `AOC_BIN_YEAR_2023_01=2023`.
+    let bins = fs::read_dir(&bin_dir)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter_map(|entry| entry.file_name().into_string().ok());
+
+    for file_name in bins {
+        let Some(bin) = file_name.strip_suffix(".rs") else {
+            continue;
+        };
+
+        if let Some((year, _)) = bin.split_once('_') {
+            println!("cargo:rustc-env=AOC_BIN_YEAR_{bin}={year}");
+        }
+    }
+
+    let data_dir = Path::new(&manifest_dir).join("data");
+
+    // re-run when a year is added.
+    println!("cargo:rerun-if-changed={}", data_dir.display());
+
+    let Ok(entries) = fs::read_dir(&data_dir) else {
+        return;
+    };
+
+    // every year has its own folder, e.g. `data/2023/inputs`. years of events other than
+    // advent of code are prefixed with the event, e.g. `data/ec2024/inputs`.
+    let years = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|name| {
+            let number = name.trim_start_matches(|c: char| c.is_ascii_lowercase());
+            number.len() == 4 && number.bytes().all(|b| b.is_ascii_digit())
+        });
+
+    for year in years {
+        let inputs_dir = data_dir.join(&year).join("inputs");
+
+        // re-run when inputs are downloaded or changed.
+        println!("cargo:rerun-if-changed={}", inputs_dir.display());
+
+        for day in 1..=25 {
+            let path = inputs_dir.join(format!("{day:02}.txt"));
+
+            // only set for present inputs, a missing input fails the build of its bin with a message.
+            if path.is_file() {
+                println!("cargo:rerun-if-changed={}", path.display());
+                // named after the bin of the day, e.g. `AOC_INPUT_2023_01`.
+                println!(
+                    "cargo:rustc-env=AOC_INPUT_{year}_{day:02}={}",
+                    path.display()
+                );
+            }
+        }
+    }
+}
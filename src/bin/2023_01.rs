@@ -0,0 +1,3 @@
+use advent_of_code::days::y2023::day01::*;
+
+advent_of_code::solution!(1, part_two: [part_two_manual]);
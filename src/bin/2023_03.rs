@@ -0,0 +1,3 @@
+use advent_of_code::days::y2023::day03::*;
+
+advent_of_code::solution!(3);
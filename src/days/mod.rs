@@ -0,0 +1,7 @@
+// Generated by `cargo scaffold`, every year is a module of the library.
+use crate::template::registry::Solution;
+
+pub mod y2023;
+
+/// The solutions of all years, in order.
+pub const SOLUTIONS: &[&[Solution]] = &[y2023::SOLUTIONS];
@@ -1,6 +1,6 @@
 use regex::Regex;
 
-advent_of_code::solution!(1);
+crate::solution!(lib 1);
 
 pub fn part_one(input: &str) -> Option<u32> {
     let v = input
@@ -8,7 +8,7 @@ pub fn part_one(input: &str) -> Option<u32> {
         .map(|ln| {
             let mut parsed = ln.chars().filter_map(|c| c.to_digit(10));
             let first = parsed.next().unwrap_or(0);
-            let last = parsed.last().unwrap_or(first);
+            let last = parsed.next_back().unwrap_or(first);
             first * 10 + last
         })
         .sum();
@@ -42,7 +42,7 @@ pub fn part_two(input: &str) -> Option<u32> {
                 });
 
             let first = parsed.next().unwrap_or(0);
-            let last = parsed.last().unwrap_or(first);
+            let last = parsed.next_back().unwrap_or(first);
             first * 10 + last
         })
         .sum();
@@ -50,23 +50,40 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(v)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Hand-written alternative to the regex-based matcher in [`part_two`].
+pub fn part_two_manual(input: &str) -> Option<u32> {
+    const DIGITS: [&str; 9] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
-        ));
-        assert_eq!(result, Some(142));
-    }
+    let v = input
+        .lines()
+        .map(|ln| {
+            let bytes = ln.as_bytes();
+            let mut parsed = (0..bytes.len()).filter_map(|i| {
+                if bytes[i].is_ascii_digit() {
+                    return Some(u32::from(bytes[i] - b'0'));
+                }
+                DIGITS
+                    .iter()
+                    .zip(1..)
+                    .find(|(digit, _)| bytes[i..].starts_with(digit.as_bytes()))
+                    .map(|(_, value)| value)
+            });
+
+            let first = parsed.next().unwrap_or(0);
+            let last = parsed.next_back().unwrap_or(first);
+            first * 10 + last
+        })
+        .sum();
+
+    Some(v)
+}
+
+crate::examples!(manifest: [part_one, part_two, part_two_manual]);
 
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
-        ));
-        assert_eq!(result, Some(281));
-    }
+crate::examples! {
+    part_two: inline "two1nine" => 29,
+    part_two: inline "eightwothree" => 83,
+    part_two_manual: inline "eightwothree" => 83,
 }
@@ -1,17 +1,17 @@
 use itertools::Itertools;
 use std::str::FromStr;
 
-advent_of_code::solution!(2);
+crate::solution!(lib 2);
 
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
-struct Game {
+pub struct Game {
     id: u32,
     sets: Vec<Set>,
 }
 
 #[allow(clippy::enum_variant_names)]
 #[derive(Debug, PartialEq, Eq, Clone)]
-enum ParseGameError {
+pub enum ParseGameError {
     InvalidFormat,
     InvalidId(String),
     InvalidSet(ParseSetError),
@@ -43,14 +43,14 @@ impl FromStr for Game {
 }
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
-struct Set {
+pub struct Set {
     red: u32,
     green: u32,
     blue: u32,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-enum ParseSetError {
+pub enum ParseSetError {
     CubeAlreadyDefined,
     InvalidCube(ParseCubeError),
 }
@@ -89,21 +89,21 @@ impl FromStr for Set {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-struct Cube {
+pub struct Cube {
     n: u32,
     color: CubeColor,
 }
 
 #[allow(clippy::enum_variant_names)]
 #[derive(Debug, PartialEq, Eq, Clone)]
-enum ParseCubeError {
+pub enum ParseCubeError {
     InvalidFormat,
     InvalidNumber(String),
     InvalidColor(String),
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-enum CubeColor {
+pub enum CubeColor {
     Red,
     Green,
     Blue,
@@ -238,16 +238,6 @@ mod tests {
             }
         );
     }
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(8));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(2286));
-    }
 }
+
+crate::examples!(manifest: [part_one, part_two]);
@@ -2,7 +2,7 @@ use std::{iter::Enumerate, slice::Iter};
 
 use itertools::Itertools;
 
-advent_of_code::solution!(3);
+crate::solution!(lib 3);
 
 /// An iterator over a 2D matrix that returns the current item, its position within the matrix, its position within
 /// the window and the window itself.
@@ -18,7 +18,7 @@ advent_of_code::solution!(3);
 ///
 ///
 #[derive(Debug)]
-struct WindowIterator<'a, T: 'a> {
+pub struct WindowIterator<'a, T: 'a> {
     size: (usize, usize),
     data: &'a Vec<Vec<T>>,
 
@@ -109,7 +109,7 @@ impl<'a, T> Iterator for WindowIterator<'a, T> {
 // }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-enum Adjacency {
+pub enum Adjacency {
     Adjacent(char),
     Isolated(char),
 }
@@ -139,7 +139,7 @@ impl std::fmt::Display for Adjacency {
     }
 }
 
-fn build_adjacency_matrix(input: &str) -> Vec<Vec<Adjacency>> {
+pub fn build_adjacency_matrix(input: &str) -> Vec<Vec<Adjacency>> {
     let matrix = input
         .lines()
         .map(|ln| ln.chars().collect_vec())
@@ -200,7 +200,7 @@ pub fn part_one(input: &str) -> Option<u32> {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-enum Parsed {
+pub enum Parsed {
     Number((u32, u32)),
     Gear,
 }
@@ -291,19 +291,4 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(v)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(4361));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(467835));
-    }
-}
+crate::examples!(manifest: [part_one, part_two]);
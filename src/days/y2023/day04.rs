@@ -2,10 +2,10 @@ use std::str::FromStr;
 
 use itertools::Itertools;
 
-advent_of_code::solution!(4);
+crate::solution!(lib 4);
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
-struct Card {
+pub struct Card {
     winning_numbers: Vec<u32>,
     numbers: Vec<u32>,
 }
@@ -72,7 +72,7 @@ impl FromStr for Card {
     }
 }
 
-fn parse_input(input: &str) -> Vec<Card> {
+pub fn parse_input(input: &str) -> Vec<Card> {
     input
         .lines()
         .map(|ln| ln.parse::<Card>().expect("card are always valid"))
@@ -107,19 +107,4 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(acc)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(13));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(30));
-    }
-}
+crate::examples!(manifest: [part_one, part_two]);
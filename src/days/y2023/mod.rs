@@ -0,0 +1,18 @@
+// Generated by `cargo scaffold`, every day of 2023 is a module of the library.
+use crate::template::{registry::Solution, Year};
+
+/// The year of the days in this module.
+pub const YEAR: Year = crate::year!(2023);
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+
+/// The solutions of all days of 2023, in order.
+pub const SOLUTIONS: &[Solution] = &[
+    day01::SOLUTION,
+    day02::SOLUTION,
+    day03::SOLUTION,
+    day04::SOLUTION,
+];
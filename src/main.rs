@@ -1,14 +1,25 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, check_examples, compare, download, extract_examples, harvest_answers, read, scaffold,
+    solve, time,
+};
 use args::{parse, AppArguments};
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
-#[cfg(feature = "today")]
-use std::process;
+use advent_of_code::template::commands::today;
 
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::{
+        commands::solve::Submission,
+        output::{set_verbosity, Verbosity},
+        paths::set_root,
+        profiles::{parse_profiles, Profile},
+        report::ReportFormat,
+        runner::enable_normalization,
+        scheduling::SchedulingOptions,
+        selection::DaySpec,
+        set_year, Day, Year,
+    };
+    use std::{path::PathBuf, process};
 
     pub enum AppArguments {
         Download {
@@ -26,34 +37,124 @@ mod args {
             release: bool,
             time: bool,
             dhat: bool,
-            submit: Option<u8>,
+            submit: Option<Submission>,
+            variants: bool,
+            example: bool,
+            scheduling: SchedulingOptions,
         },
         All {
+            days: DaySpec,
             release: bool,
             time: bool,
+            scheduling: SchedulingOptions,
+            report: Option<(ReportFormat, String)>,
         },
         Time {
             all: bool,
-            day: Option<Day>,
+            criterion: bool,
+            days: Option<DaySpec>,
+            profiles: Option<Vec<Profile>>,
+            store: bool,
+            scheduling: SchedulingOptions,
+        },
+        Compare {
+            git_ref: String,
+            days: DaySpec,
+        },
+        CheckExamples {
+            days: DaySpec,
+        },
+        ExtractExamples {
+            day: Day,
+        },
+        HarvestAnswers {
+            days: Option<DaySpec>,
+            offline: bool,
         },
         #[cfg(feature = "today")]
-        Today,
+        Today {
+            wait: bool,
+        },
+    }
+
+    fn parse_scheduling(
+        args: &mut pico_args::Arguments,
+    ) -> Result<SchedulingOptions, Box<dyn std::error::Error>> {
+        Ok(SchedulingOptions {
+            cpu: args.opt_value_from_str("--pin")?,
+            high_priority: args.contains("--high-priority"),
+        })
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
+        // global flags, applied before the command runs.
+        if let Some(root) = args.opt_value_from_str::<_, PathBuf>("--root")? {
+            set_root(&root);
+        }
+
+        if let Some(year) = args.opt_value_from_str::<_, Year>("--year")? {
+            set_year(year);
+        }
+
+        if args.contains("--normalize") {
+            enable_normalization();
+        }
+
+        match (
+            args.contains(["-q", "--quiet"]),
+            args.contains(["-v", "--verbose"]),
+        ) {
+            (true, true) => {
+                eprintln!("`--quiet` and `--verbose` can not be used together.");
+                process::exit(1);
+            }
+            (true, false) => set_verbosity(Verbosity::Quiet),
+            (false, true) => set_verbosity(Verbosity::Verbose),
+            (false, false) => {}
+        }
+
         let app_args = match args.subcommand()?.as_deref() {
-            Some("all") => AppArguments::All {
-                release: args.contains("--release"),
-                time: args.contains("--time"),
-            },
+            Some("all") => {
+                let report = match (
+                    args.opt_value_from_str::<_, ReportFormat>("--report")?,
+                    args.opt_value_from_str::<_, String>("--out")?,
+                ) {
+                    (Some(format), Some(out)) => Some((format, out)),
+                    (None, None) => None,
+                    _ => {
+                        eprintln!("`--report` and `--out` need to be used together.");
+                        process::exit(1);
+                    }
+                };
+
+                let release = args.contains("--release");
+                let time = args.contains("--time");
+                let scheduling = parse_scheduling(&mut args)?;
+
+                AppArguments::All {
+                    days: args.opt_free_from_str()?.unwrap_or_else(DaySpec::all),
+                    release,
+                    time,
+                    scheduling,
+                    report,
+                }
+            }
             Some("time") => {
                 let all = args.contains("--all");
+                let profiles = args.opt_value_from_fn("--profiles", parse_profiles)?;
+                let store = args.contains("--store");
+                let criterion = args.contains("--criterion");
+                let scheduling = parse_scheduling(&mut args)?;
 
                 AppArguments::Time {
                     all,
-                    day: args.opt_free_from_str()?,
+                    criterion,
+                    days: args.opt_free_from_str()?,
+                    profiles,
+                    store,
+                    scheduling,
                 }
             }
             Some("download") => AppArguments::Download {
@@ -66,15 +167,48 @@ mod args {
                 day: args.free_from_str()?,
                 download: args.contains("--download"),
             },
-            Some("solve") => AppArguments::Solve {
+            Some("solve") => {
+                let force = args.contains("--force");
+                let submit: Option<u8> = args.opt_value_from_str("--submit")?;
+
+                if force && submit.is_none() {
+                    eprintln!("`--force` can only be used together with `--submit`.");
+                    process::exit(1);
+                }
+
+                AppArguments::Solve {
+                    day: args.free_from_str()?,
+                    release: args.contains("--release"),
+                    submit: submit.map(|part| Submission { part, force }),
+                    time: args.contains("--time"),
+                    dhat: args.contains("--dhat"),
+                    variants: args.contains("--variants"),
+                    example: args.contains("--example"),
+                    scheduling: parse_scheduling(&mut args)?,
+                }
+            }
+            Some("compare") => {
+                let git_ref = args.free_from_str()?;
+                let days = args.opt_free_from_str()?.unwrap_or_else(DaySpec::all);
+                AppArguments::Compare { git_ref, days }
+            }
+            Some("check-examples") => AppArguments::CheckExamples {
+                days: args.opt_free_from_str()?.unwrap_or_else(DaySpec::all),
+            },
+            Some("extract-examples") => AppArguments::ExtractExamples {
                 day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                time: args.contains("--time"),
-                dhat: args.contains("--dhat"),
             },
+            Some("harvest-answers") => {
+                let offline = args.contains("--offline");
+                AppArguments::HarvestAnswers {
+                    days: args.opt_free_from_str()?,
+                    offline,
+                }
+            }
             #[cfg(feature = "today")]
-            Some("today") => AppArguments::Today,
+            Some("today") => AppArguments::Today {
+                wait: args.contains("--wait"),
+            },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
                 process::exit(1);
@@ -101,8 +235,21 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release, time } => all::handle(release, time),
-            AppArguments::Time { day, all } => time::handle(day, all),
+            AppArguments::All {
+                days,
+                release,
+                time,
+                scheduling,
+                report,
+            } => all::handle(&days, release, time, &scheduling, report),
+            AppArguments::Time {
+                days,
+                all,
+                criterion,
+                profiles,
+                store,
+                scheduling,
+            } => time::handle(days, all, criterion, profiles, store, &scheduling),
             AppArguments::Download { day } => download::handle(day),
             AppArguments::Read { day } => read::handle(day),
             AppArguments::Scaffold { day, download } => {
@@ -111,30 +258,30 @@ fn main() {
                     download::handle(day);
                 }
             }
+            AppArguments::Solve {
+                day,
+                release,
+                example: true,
+                ..
+            } => solve::handle_examples(day, release),
             AppArguments::Solve {
                 day,
                 release,
                 time,
                 dhat,
                 submit,
-            } => solve::handle(day, release, time, dhat, submit),
-            #[cfg(feature = "today")]
-            AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day);
-                        download::handle(day);
-                        read::handle(day)
-                    }
-                    None => {
-                        eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
-                        );
-                        process::exit(1)
-                    }
-                };
+                variants,
+                example: false,
+                scheduling,
+            } => solve::handle(day, release, time, dhat, submit, variants, &scheduling),
+            AppArguments::Compare { git_ref, days } => compare::handle(&git_ref, &days),
+            AppArguments::CheckExamples { days } => check_examples::handle(&days),
+            AppArguments::ExtractExamples { day } => extract_examples::handle(day),
+            AppArguments::HarvestAnswers { days, offline } => {
+                harvest_answers::handle(days, offline)
             }
+            #[cfg(feature = "today")]
+            AppArguments::Today { wait } => today::handle(wait),
         },
     };
 }
@@ -0,0 +1,258 @@
+use std::{collections::HashMap, fs, io::Error, path::PathBuf, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{current_year, paths, Day, PART_NAMES};
+
+fn get_path() -> PathBuf {
+    paths::data_file("answers.json")
+}
+
+/// Represents the known correct answers for a single day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Answer {
+    pub day: Day,
+    /// Answers of the parts, in order. Unknown answers are `None`.
+    pub parts: Vec<Option<String>>,
+}
+
+/// Represents the known correct answers for a set of days, used to verify solutions.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub data: Vec<Answer>,
+}
+
+impl Answers {
+    /// Dehydrate answers to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(get_path())?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from a JSON file. If not present, returns empty answers.
+    pub fn read_from_file() -> Self {
+        let path = get_path();
+        let Ok(s) = fs::read_to_string(&path) else {
+            return Answers::default();
+        };
+
+        match Answers::try_from(s) {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("{}: {e}", path.display());
+                Answers::default()
+            }
+        }
+    }
+
+    /// The known answer for a part of a day, if any.
+    pub fn get(&self, day: Day, part: u8) -> Option<&str> {
+        let answer = self.data.iter().find(|a| a.day == day)?;
+        let index = usize::from(part).checked_sub(1)?;
+        answer.parts.get(index)?.as_deref()
+    }
+
+    /// Whether the answers of all parts of a day of the current year are known.
+    pub fn is_day_complete(&self, day: Day) -> bool {
+        (1..=current_year().part_count()).all(|part| self.get(day, part).is_some())
+    }
+
+    /// Record the answer for a part of a day, overwriting previous values.
+    pub fn set(&mut self, day: Day, part: u8, value: &str) {
+        let index = match self.data.iter().position(|a| a.day == day) {
+            Some(index) => index,
+            None => {
+                self.data.push(Answer { day, parts: vec![] });
+                self.data.sort_unstable_by_key(|a| a.day);
+                self.data.iter().position(|a| a.day == day).unwrap()
+            }
+        };
+
+        let Some(part_index) = usize::from(part).checked_sub(1) else {
+            return;
+        };
+
+        let parts = &mut self.data[index].parts;
+        if parts.len() <= part_index {
+            parts.resize(part_index + 1, None);
+        }
+        parts[part_index] = Some(value.into());
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Answers> for JsonValue {
+    fn from(value: Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Answers {
+            data: json_data
+                .iter()
+                .map(Answer::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answer> for JsonValue {
+    fn from(value: &Answer) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+
+        for part in 1..=value.parts.len().max(2) {
+            map.insert(
+                format!("part_{part}"),
+                value
+                    .parts
+                    .get(part - 1)
+                    .cloned()
+                    .flatten()
+                    .map_or(JsonValue::Null, JsonValue::String),
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+/// Answers may be stored as strings or numbers. Large numbers should be stored as strings,
+/// JSON numbers are only precise up to 2^53.
+pub(crate) fn parse_answer(value: &JsonValue) -> Option<Option<String>> {
+    match value {
+        JsonValue::Null => Some(None),
+        JsonValue::String(s) => Some(Some(s.clone())),
+        #[allow(clippy::cast_possible_truncation)]
+        JsonValue::Number(n) if n.fract() == 0.0 => Some(Some((*n as i64).to_string())),
+        _ => None,
+    }
+}
+
+/// Read the answers of all parts from the keys `part_1`, `part_2`, ... of a JSON object. Missing
+/// keys are unknown answers, trailing unknown answers are dropped. `kind` names the object in
+/// error messages.
+pub(crate) fn parse_parts(
+    json: &HashMap<String, JsonValue>,
+    kind: &str,
+) -> Result<Vec<Option<String>>, String> {
+    let mut parts = (1..=PART_NAMES.len())
+        .map(|part| {
+            let key = format!("part_{part}");
+            json.get(&key)
+                .map_or(Some(None), parse_answer)
+                .ok_or(format!(
+                    "Expected {kind}.{key} to be null, string or integer."
+                ))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    while parts.last() == Some(&None) {
+        parts.pop();
+    }
+
+    Ok(parts)
+}
+
+impl TryFrom<&JsonValue> for Answer {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answer to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected answer.day to be a Day struct.")?;
+
+        let parts = parse_parts(json, "answer")?;
+
+        Ok(Answer { day, parts })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Answers;
+    use crate::day;
+    use tinyjson::JsonValue;
+
+    #[test]
+    fn handles_json_answers() {
+        let json =
+            r#"{ "data": [{ "day": "01", "part_1": "142", "part_2": 281 }, { "day": "02" }] }"#
+                .to_string();
+        let answers = Answers::try_from(json).unwrap();
+        assert_eq!(answers.get(day!(1), 1), Some("142"));
+        assert_eq!(answers.get(day!(1), 2), Some("281"));
+        assert_eq!(answers.get(day!(2), 1), None);
+        assert_eq!(answers.get(day!(3), 1), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_answers() {
+        let json = r#"{ "data": [{ "day": "01", "part_1": 1.5 }] }"#.to_string();
+        Answers::try_from(json).unwrap();
+    }
+
+    #[test]
+    fn sets_answers() {
+        let mut answers = Answers::default();
+        answers.set(day!(4), 2, "30");
+        answers.set(day!(1), 1, "142");
+        answers.set(day!(1), 1, "143");
+        assert_eq!(answers.data.len(), 2);
+        assert_eq!(answers.data[0].day, day!(1));
+        assert_eq!(answers.get(day!(1), 1), Some("143"));
+        assert_eq!(answers.get(day!(4), 2), Some("30"));
+    }
+
+    #[test]
+    fn handles_three_parts() {
+        let json = r#"{ "data": [{ "day": "01", "part_1": 4, "part_3": "12" }] }"#.to_string();
+        let answers = Answers::try_from(json).unwrap();
+        assert_eq!(answers.get(day!(1), 2), None);
+        assert_eq!(answers.get(day!(1), 3), Some("12"));
+    }
+
+    #[test]
+    fn roundtrips_answers() {
+        let mut answers = Answers::default();
+        answers.set(day!(1), 1, "142");
+        answers.set(day!(2), 3, "12");
+        let json = JsonValue::from(answers.clone()).stringify().unwrap();
+        assert_eq!(Answers::try_from(json).unwrap(), answers);
+    }
+}
@@ -4,10 +4,14 @@ use std::{
     process::{Command, Output, Stdio},
 };
 
-use crate::template::Day;
+use crate::template::{current_year, output, paths, puzzle, Backend, Day, Event};
 
 #[derive(Debug)]
 pub enum AocCommandError {
+    /// The event of the current year is not on adventofcode.com, see [`Event::backend`].
+    UnsupportedEvent(Event),
+    /// The puzzle of the day has not unlocked yet, see [`Day::unlock_time`].
+    Locked(Day),
     CommandNotFound,
     CommandNotCallable,
     BadExitStatus(Output),
@@ -16,7 +20,21 @@ pub enum AocCommandError {
 impl Display for AocCommandError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            AocCommandError::CommandNotFound => write!(f, "aoc-cli is not present in environment."),
+            AocCommandError::UnsupportedEvent(event) => write!(
+                f,
+                "aoc-cli only supports Advent of Code, save the inputs of {event} to \"{}\" and submit answers by hand.",
+                paths::data_dir("inputs").display()
+            ),
+            AocCommandError::Locked(day) => write!(
+                f,
+                "day {day} of {year} is not unlocked yet, it unlocks on december {}, {year} at midnight EST (UTC-5).",
+                day.into_inner(),
+                year = current_year()
+            ),
+            AocCommandError::CommandNotFound => write!(
+                f,
+                "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+            ),
             AocCommandError::CommandNotCallable => write!(f, "aoc-cli could not be called."),
             AocCommandError::BadExitStatus(_) => {
                 write!(f, "aoc-cli exited with a non-zero status.")
@@ -25,7 +43,13 @@ impl Display for AocCommandError {
     }
 }
 
+/// Check that aoc-cli is installed and supports the event of the current year.
 pub fn check() -> Result<(), AocCommandError> {
+    let event = current_year().event();
+    if event.backend() != Backend::AocCli {
+        return Err(AocCommandError::UnsupportedEvent(event));
+    }
+
     Command::new("aoc")
         .arg("-V")
         .output()
@@ -33,6 +57,16 @@ pub fn check() -> Result<(), AocCommandError> {
     Ok(())
 }
 
+/// Check that the puzzle of a day of the current year is unlocked, i.e. that its input can be
+/// downloaded and its answers submitted.
+pub fn check_unlocked(day: Day) -> Result<(), AocCommandError> {
+    if day.is_unlocked() {
+        Ok(())
+    } else {
+        Err(AocCommandError::Locked(day))
+    }
+}
+
 pub fn read(day: Day) -> Result<Output, AocCommandError> {
     let puzzle_path = get_puzzle_path(day);
 
@@ -66,42 +100,73 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
     );
 
     let output = call_aoc_cli(&args)?;
-    println!("---");
-    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    if !output::is_quiet() {
+        println!("---");
+        println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+        println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    }
     Ok(output)
 }
 
+/// Download only the puzzle description, e.g. to pick up the answers shown after solving a part.
+pub fn download_puzzle(day: Day) -> Result<Output, AocCommandError> {
+    let args = build_args(
+        "download",
+        &[
+            "--overwrite".into(),
+            "--puzzle-only".into(),
+            "--puzzle-file".into(),
+            get_puzzle_path(day),
+        ],
+        day,
+    );
+
+    call_aoc_cli(&args)
+}
+
+/// Submit an answer. The output of aoc-cli is captured to tell whether the answer was correct,
+/// see [`is_correct_answer`], and printed afterwards.
 pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
     // workaround: the argument order is inverted for submit.
     let mut args = build_args("submit", &[], day);
     args.push(part.to_string());
     args.push(result.to_string());
-    call_aoc_cli(&args)
+
+    let output = Command::new("aoc")
+        .args(&args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|_| AocCommandError::CommandNotCallable)?;
+
+    print!("{}", String::from_utf8_lossy(&output.stdout));
+
+    if output.status.success() {
+        Ok(output)
+    } else {
+        Err(AocCommandError::BadExitStatus(output))
+    }
 }
 
-fn get_input_path(day: Day) -> String {
-    format!("data/inputs/{day}.txt")
+/// Whether the output of a submission reports a correct answer.
+pub fn is_correct_answer(output: &Output) -> bool {
+    String::from_utf8_lossy(&output.stdout).contains("That's the right answer")
 }
 
-fn get_puzzle_path(day: Day) -> String {
-    format!("data/puzzles/{day}.md")
+fn get_input_path(day: Day) -> String {
+    let path = paths::data_dir("inputs").join(format!("{day}.txt"));
+    path.to_string_lossy().into_owned()
 }
 
-fn get_year() -> Option<u16> {
-    match std::env::var("AOC_YEAR") {
-        Ok(x) => x.parse().ok().or(None),
-        Err(_) => None,
-    }
+fn get_puzzle_path(day: Day) -> String {
+    puzzle::puzzle_path(day).to_string_lossy().into_owned()
 }
 
 fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
     let mut cmd_args = args.to_vec();
 
-    if let Some(year) = get_year() {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
-    }
+    cmd_args.push("--year".into());
+    cmd_args.push(current_year().into_inner().to_string());
 
     cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
 
@@ -0,0 +1,117 @@
+/// Import of the reports that `cargo bench` writes, see `benches/days.rs`.
+use std::{
+    collections::{HashMap, HashSet},
+    env, fs,
+    path::PathBuf,
+    str::FromStr,
+    time::Duration,
+};
+use tinyjson::JsonValue;
+
+use crate::template::timings::{Timing, Timings};
+use crate::template::{current_year, paths, sorted_days, Day, Year};
+
+/// Criterion writes its reports to `target/criterion`, unless the target directory is overridden.
+fn reports_dir() -> PathBuf {
+    env::var_os("CARGO_TARGET_DIR")
+        .map_or_else(|| paths::root().join("target"), PathBuf::from)
+        .join("criterion")
+}
+
+/// Name of the benchmark group of a day of a year, e.g. `2023_day01`.
+pub fn group_name(year: Year, day: Day) -> String {
+    format!("{year}_day{day}")
+}
+
+/// Name of the benchmark of a part, e.g. `part_1`.
+pub fn part_name(part: u8) -> String {
+    format!("part_{part}")
+}
+
+/// Read the mean time of the latest bench of a part, if it was benched.
+fn read_mean(year: Year, day: Day, part: u8) -> Option<Result<f64, String>> {
+    let path = reports_dir()
+        .join(group_name(year, day))
+        .join(part_name(part))
+        .join("new")
+        .join("estimates.json");
+
+    let contents = fs::read_to_string(&path).ok()?;
+    Some(parse_mean(&contents).map_err(|e| format!("{}: {e}", path.display())))
+}
+
+/// Extract the mean in nanoseconds from the `estimates.json` of a criterion bench.
+fn parse_mean(contents: &str) -> Result<f64, String> {
+    let json = JsonValue::from_str(contents).or(Err("not valid JSON file."))?;
+
+    json.get::<HashMap<String, JsonValue>>()
+        .and_then(|estimates| estimates.get("mean"))
+        .and_then(|mean| mean.get::<HashMap<String, JsonValue>>())
+        .and_then(|mean| mean.get("point_estimate"))
+        .and_then(|estimate| estimate.get::<f64>())
+        .copied()
+        .ok_or_else(|| "expected `json.mean.point_estimate` to be a number.".into())
+}
+
+/// Format a mean the same way the runner formats timings, e.g. `17.3µs`.
+fn format_mean(nanos: f64) -> String {
+    format!("{:.1?}", Duration::from_nanos(nanos as u64))
+}
+
+/// Read the timings of the selected days of the current year from the reports of `cargo bench`.
+/// Days without reports are left out.
+pub fn read_timings(days: &HashSet<Day>) -> Timings {
+    let year = current_year();
+    let mut data = vec![];
+
+    for day in sorted_days(days) {
+        let mut timing = Timing {
+            day,
+            parts: vec![],
+            total_nanos: 0_f64,
+            scheduling: None,
+        };
+
+        for part in 1..=year.part_count() {
+            let formatted = match read_mean(year, day, part) {
+                Some(Ok(nanos)) => {
+                    timing.total_nanos += nanos;
+                    Some(format_mean(nanos))
+                }
+                Some(Err(e)) => {
+                    eprintln!("{e}");
+                    None
+                }
+                None => None,
+            };
+            timing.parts.push(formatted);
+        }
+
+        if timing.parts.iter().any(Option::is_some) {
+            data.push(timing);
+        }
+    }
+
+    Timings { data }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_mean, parse_mean};
+
+    #[test]
+    fn parses_criterion_estimates() {
+        let estimates = r#"{"mean":{"confidence_interval":{"confidence_level":0.95,"lower_bound":17100.5,"upper_bound":17600.1},"point_estimate":17345.2,"standard_error":120.4},"median":{"point_estimate":17300.0}}"#;
+        assert_eq!(parse_mean(estimates), Ok(17345.2));
+        assert!(parse_mean(r#"{"median":{"point_estimate":1.0}}"#).is_err());
+        assert!(parse_mean("").is_err());
+    }
+
+    #[test]
+    fn formats_means_like_the_runner() {
+        assert_eq!(format_mean(17345.2), "17.3µs");
+        assert_eq!(format_mean(1_600_000.0), "1.6ms");
+    }
+}
@@ -1,5 +1,41 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::process;
 
-pub fn handle(is_release: bool, is_timed: bool) {
-    run_multi(&all_days().collect(), is_release, is_timed);
+use crate::template::{
+    output,
+    profiles::Profile,
+    report::{write_report, ReportFormat},
+    run_multi::run_multi,
+    scheduling::SchedulingOptions,
+    selection::DaySpec,
+};
+
+pub fn handle(
+    days: &DaySpec,
+    is_release: bool,
+    is_timed: bool,
+    scheduling: &SchedulingOptions,
+    report: Option<(ReportFormat, String)>,
+) {
+    let profile = if is_release {
+        Profile::release()
+    } else {
+        Profile::dev()
+    };
+
+    let days_to_run = days.resolve_from_files();
+    let summary = run_multi(&days_to_run, &profile, is_timed, scheduling);
+
+    if let Some((format, path)) = report {
+        match write_report(&summary.days, format, &path) {
+            Ok(()) => {
+                if !output::is_quiet() {
+                    println!("Wrote report to \"{path}\".");
+                }
+            }
+            Err(e) => {
+                eprintln!("Failed to write report: {e}");
+                process::exit(1);
+            }
+        }
+    }
 }
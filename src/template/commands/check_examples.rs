@@ -0,0 +1,66 @@
+use std::process;
+
+use crate::template::examples::{check_part, ExamplesManifest};
+use crate::template::output::{self, bold};
+use crate::template::{current_year, paths, registry, selection::DaySpec, sorted_days};
+
+/// Check the selected days against all examples listed in `data/<year>/examples.json`.
+/// Exits with an error if any example fails.
+pub fn handle(days: &DaySpec) {
+    let year = current_year();
+    let manifest = ExamplesManifest::read_from_file();
+    let days = days.resolve_from_files();
+
+    let mut checked = 0;
+    let mut failed = 0;
+
+    for day in sorted_days(&days) {
+        let Some(solution) = registry::find(year, day) else {
+            continue;
+        };
+
+        let checks: Vec<_> = solution
+            .parts
+            .iter()
+            .flat_map(|part| check_part(&manifest, year, day, part.number, part.answer))
+            .collect();
+
+        if checks.is_empty() {
+            continue;
+        }
+
+        checked += checks.len();
+        failed += checks.iter().filter(|check| !check.is_passed()).count();
+
+        if !output::is_quiet() {
+            println!("{}", bold(format!("{} {day}", year.event().day_label())));
+            println!("------");
+        }
+
+        for check in checks {
+            if !output::is_quiet() || !check.is_passed() {
+                println!("{check}");
+            }
+        }
+
+        if !output::is_quiet() {
+            println!();
+        }
+    }
+
+    if checked == 0 {
+        eprintln!(
+            "No examples with expected answers found in \"{}\".",
+            paths::data_file("examples.json").display()
+        );
+        process::exit(1);
+    }
+
+    if !output::is_quiet() {
+        println!("{} of {checked} examples passed.", checked - failed);
+    }
+
+    if failed > 0 {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,293 @@
+use std::{
+    collections::HashMap,
+    env, fs, io,
+    path::{Path, PathBuf},
+    process::{self, Command, Stdio},
+};
+
+use crate::template::comparison::print_comparison;
+use crate::template::run_multi::child_commands::{
+    parse_build_messages, parse_exec_time, parse_parts, ParsedPart,
+};
+use crate::template::selection::DaySpec;
+use crate::template::timings::Timings;
+use crate::template::{
+    output::{self, bold},
+    paths, sorted_days, Day,
+};
+
+/// A temporary git worktree that is removed again when dropped.
+struct Worktree {
+    path: PathBuf,
+}
+
+impl Worktree {
+    fn create(git_ref: &str) -> Result<Self, String> {
+        let path = env::temp_dir().join(format!("aoc-compare-{}", process::id()));
+
+        let status = Command::new("git")
+            .current_dir(paths::root())
+            .args(["worktree", "add", "--detach"])
+            .arg(&path)
+            .arg(git_ref)
+            .stdout(Stdio::null())
+            .status()
+            .map_err(|e| format!("could not call git: {e}"))?;
+
+        if status.success() {
+            Ok(Self { path })
+        } else {
+            Err(format!("could not check out `{git_ref}` into a worktree."))
+        }
+    }
+}
+
+impl Drop for Worktree {
+    fn drop(&mut self) {
+        let removed = Command::new("git")
+            .current_dir(paths::root())
+            .args(["worktree", "remove", "--force"])
+            .arg(&self.path)
+            .status()
+            .is_ok_and(|status| status.success());
+
+        if !removed {
+            eprintln!(
+                "Failed to remove worktree \"{}\", run `git worktree prune` to clean up.",
+                self.path.display()
+            );
+        }
+    }
+}
+
+/// A source tree whose solutions are compared, i.e. the working tree or the checked out ref.
+struct Tree {
+    dir: PathBuf,
+    target_dir: PathBuf,
+}
+
+impl Tree {
+    /// Name of the solution bin of a day in this tree, `None` if the tree does not contain the day.
+    /// Trees that predate years name their bins after the day only, e.g. `01`.
+    fn bin_name(&self, day: Day) -> Option<String> {
+        [paths::bin_name(day), day.to_string()]
+            .into_iter()
+            .find(|name| {
+                self.dir
+                    .join("src")
+                    .join("bin")
+                    .join(format!("{name}.rs"))
+                    .is_file()
+            })
+    }
+
+    /// Build the given bins and return the executable of every day that compiled.
+    fn build(&self, bin_names: &[String]) -> Result<HashMap<Day, PathBuf>, String> {
+        let mut cmd = Command::new("cargo");
+        cmd.current_dir(&self.dir)
+            .args(["build", "--quiet", "--release", "--message-format=json"])
+            .arg("--target-dir")
+            .arg(&self.target_dir);
+
+        for name in bin_names {
+            cmd.arg("--bin").arg(name);
+        }
+
+        let output = cmd
+            .stderr(Stdio::inherit())
+            .output()
+            .map_err(|e| format!("could not call cargo: {e}"))?;
+
+        let (builds, diagnostics) =
+            parse_build_messages(String::from_utf8_lossy(&output.stdout).lines());
+
+        if !output.status.success() {
+            for diagnostic in diagnostics
+                .iter()
+                .chain(builds.values().flat_map(|b| &b.diagnostics))
+            {
+                eprint!("{diagnostic}");
+            }
+            return Err(format!("Failed to build \"{}\".", self.dir.display()));
+        }
+
+        Ok(builds
+            .into_iter()
+            .filter_map(|(day, build)| Some((day, build.executable?)))
+            .collect())
+    }
+}
+
+/// Create a project root with the inputs of the current working tree in the layout of trees that
+/// predate years, i.e. in `data/inputs` instead of `data/<year>/inputs`.
+fn create_legacy_root(dir: &Path) -> Result<PathBuf, String> {
+    let error = |e: io::Error| format!("could not prepare inputs for legacy solutions: {e}");
+    let inputs = dir.join("data").join("inputs");
+    fs::create_dir_all(&inputs).map_err(error)?;
+
+    for entry in fs::read_dir(paths::data_dir("inputs")).map_err(error)? {
+        let entry = entry.map_err(error)?;
+        if entry.file_type().map_err(error)?.is_file() {
+            fs::copy(entry.path(), inputs.join(entry.file_name())).map_err(error)?;
+        }
+    }
+
+    Ok(dir.to_path_buf())
+}
+
+/// Run a solution binary against the project root `data_root` and capture its output.
+fn run(binary: &Path, data_root: &Path) -> Option<Vec<String>> {
+    let mut cmd = Command::new(binary);
+    output::configure_child(&mut cmd, false);
+
+    // binaries of the reference tree would otherwise resolve paths relative to the worktree.
+    cmd.env(paths::ROOT_ENV, data_root).current_dir(data_root);
+
+    let output = cmd.arg("--time").stderr(Stdio::inherit()).output().ok()?;
+
+    if !output.status.success() {
+        eprintln!("\"{}\" exited with {}.", binary.display(), output.status);
+    }
+
+    Some(
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(String::from)
+            .collect(),
+    )
+}
+
+pub fn handle(git_ref: &str, days: &DaySpec) {
+    let worktree = match Worktree::create(git_ref) {
+        Ok(worktree) => worktree,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    let result = compare(git_ref, days, &worktree);
+
+    // `process::exit` does not run destructors, remove the worktree first.
+    drop(worktree);
+
+    match result {
+        Ok(0) => {}
+        Ok(mismatches) => {
+            eprintln!("\n{mismatches} part(s) differ from `{git_ref}`.");
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Build both trees, run the selected days and print the comparison.
+/// Returns the number of parts whose answers differ.
+fn compare(git_ref: &str, days: &DaySpec, worktree: &Worktree) -> Result<usize, String> {
+    let root = paths::root();
+    let target_dir =
+        env::var_os("CARGO_TARGET_DIR").map_or_else(|| root.join("target"), PathBuf::from);
+
+    // the worktree is removed after every run, build it outside so that repeated comparisons
+    // build incrementally.
+    let ref_tree = Tree {
+        dir: worktree.path.clone(),
+        target_dir: target_dir.join("compare"),
+    };
+    let tree = Tree {
+        dir: root.to_path_buf(),
+        target_dir,
+    };
+
+    // days that are present in both trees, with the bin names of the reference tree.
+    let mut selected: Vec<(Day, String)> = vec![];
+
+    for day in sorted_days(&days.resolve_from_files()) {
+        match (ref_tree.bin_name(day), tree.bin_name(day)) {
+            (Some(ref_name), Some(_)) => selected.push((day, ref_name)),
+            (None, Some(_)) => eprintln!("Skipping day {day}: not present in `{git_ref}`."),
+            (_, None) => eprintln!("Skipping day {day}: not present in working tree."),
+        }
+    }
+
+    if selected.is_empty() {
+        return Err(format!(
+            "None of the selected days are present in both `{git_ref}` and the working tree."
+        ));
+    }
+
+    let ref_bin_names: Vec<String> = selected.iter().map(|(_, name)| name.clone()).collect();
+    let bin_names: Vec<String> = selected
+        .iter()
+        .map(|(day, _)| paths::bin_name(*day))
+        .collect();
+
+    if !output::is_quiet() {
+        println!("Building `{git_ref}`...");
+    }
+    let ref_executables = ref_tree.build(&ref_bin_names)?;
+
+    if !output::is_quiet() {
+        println!("Building working tree...");
+    }
+    let executables = tree.build(&bin_names)?;
+
+    // solutions of trees that predate years read their inputs from `data/inputs`.
+    let ref_root = if selected.iter().any(|(day, name)| *name == day.to_string()) {
+        create_legacy_root(&worktree.path.join(".aoc-compare"))?
+    } else {
+        root.to_path_buf()
+    };
+
+    let mut ref_timings = Timings::default();
+    let mut timings = Timings::default();
+    let mut mismatches = 0;
+
+    for (day, _) in selected {
+        println!();
+        println!("{}", bold(format!("Day {day}")));
+        println!("------");
+
+        let (Some(ref_output), Some(output)) = (
+            ref_executables
+                .get(&day)
+                .and_then(|binary| run(binary, &ref_root)),
+            executables.get(&day).and_then(|binary| run(binary, root)),
+        ) else {
+            return Err(format!("Failed to run day {day}."));
+        };
+
+        let ref_parts = parse_parts(&ref_output);
+
+        for ParsedPart { part, answer, .. } in parse_parts(&output) {
+            let ref_answer = ref_parts
+                .iter()
+                .find(|p| p.part == part)
+                .and_then(|p| p.answer.clone());
+
+            if ref_answer == answer {
+                println!("Part {part}: ✔ answers match");
+            } else {
+                mismatches += 1;
+                println!(
+                    "Part {part}: ✖ answers differ: `{}` in {git_ref}, `{}` in working tree",
+                    ref_answer.unwrap_or_else(|| "-".into()),
+                    answer.unwrap_or_else(|| "-".into()),
+                );
+            }
+        }
+
+        ref_timings.data.push(parse_exec_time(&ref_output, day));
+        timings.data.push(parse_exec_time(&output, day));
+    }
+
+    print_comparison(&[
+        (git_ref.to_string(), ref_timings),
+        ("working tree".to_string(), timings),
+    ]);
+
+    Ok(mismatches)
+}
@@ -2,8 +2,8 @@ use crate::template::{aoc_cli, Day};
 use std::process;
 
 pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+    if let Err(e) = aoc_cli::check_unlocked(day).and_then(|()| aoc_cli::check()) {
+        eprintln!("{e}");
         process::exit(1);
     }
 
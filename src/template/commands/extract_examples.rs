@@ -0,0 +1,95 @@
+use std::{fs, process};
+
+use crate::template::examples::{Example, ExamplesManifest};
+use crate::template::puzzle::{extract_examples, puzzle_path, read_puzzle};
+use crate::template::{output, paths, Day};
+
+/// Write the examples found in the puzzle description of a day to `data/<year>/examples` and
+/// their answers to `data/<year>/examples.json`. Example files that already have contents are kept.
+pub fn handle(day: Day) {
+    let Ok(puzzle) = read_puzzle(day) else {
+        eprintln!(
+            "\"{}\" does not exist. Run `cargo download {}` to download the puzzle description.",
+            puzzle_path(day).display(),
+            day.into_inner()
+        );
+        process::exit(1);
+    };
+
+    let examples = extract_examples(&puzzle);
+    if examples.is_empty() {
+        eprintln!(
+            "Found no code blocks in \"{}\".",
+            puzzle_path(day).display()
+        );
+        process::exit(1);
+    }
+
+    let mut manifest = ExamplesManifest::read_from_file();
+    let mut manifest_changed = false;
+
+    for (i, example) in examples.iter().enumerate() {
+        // a single example uses the file created by `scaffold`.
+        let file = if examples.len() == 1 {
+            day.to_string()
+        } else {
+            format!("{day}-{}", i + 1)
+        };
+
+        let path = paths::data_dir("examples").join(format!("{file}.txt"));
+        let has_contents = fs::read_to_string(&path).is_ok_and(|s| !s.trim().is_empty());
+
+        if has_contents {
+            println!("Skipped \"{}\", it already has contents.", path.display());
+            continue;
+        }
+
+        if let Err(e) = fs::write(&path, &example.input) {
+            eprintln!("Failed to write example file: {e}");
+            process::exit(1);
+        }
+
+        if !output::is_quiet() {
+            println!("Wrote example file \"{}\"", path.display());
+            for (part, answer) in [(1, &example.part_1), (2, &example.part_2)] {
+                if let Some(answer) = answer {
+                    println!("  part {part}: {answer}");
+                }
+            }
+        }
+
+        if example.part_1.is_none() && example.part_2.is_none() {
+            continue;
+        }
+
+        let added = manifest.add(Example {
+            day,
+            file: file.clone(),
+            parts: vec![example.part_1.clone(), example.part_2.clone()],
+        });
+
+        if added {
+            manifest_changed = true;
+        } else {
+            println!(
+                "Kept the existing answers of \"{file}\" in \"{}\".",
+                paths::data_file("examples.json").display()
+            );
+        }
+    }
+
+    if manifest_changed {
+        if let Err(e) = manifest.store_file() {
+            eprintln!("Failed to store examples manifest: {e}");
+            process::exit(1);
+        }
+    }
+
+    if !output::is_quiet() {
+        println!("---");
+        println!(
+            "🎄 Examples are found by heuristics. Compare them to the puzzle description, then run `cargo check-examples {}`.",
+            day.into_inner()
+        );
+    }
+}
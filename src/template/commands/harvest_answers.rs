@@ -0,0 +1,106 @@
+use std::process;
+
+use crate::template::answers::Answers;
+use crate::template::output::{self, bold};
+use crate::template::puzzle::{find_puzzle_answers, puzzle_path, read_puzzle};
+use crate::template::selection::DaySpec;
+use crate::template::{aoc_cli, current_year, paths, sorted_days, Day};
+
+/// Re-download the puzzle descriptions of the selected days and store the answers they show for
+/// solved parts in `data/<year>/answers.json`. Without a selection, all scaffolded days that lack
+/// a known answer are harvested. With `offline`, the descriptions already in
+/// `data/<year>/puzzles` are used.
+pub fn handle(days: Option<DaySpec>, offline: bool) {
+    if !offline {
+        if let Err(e) = aoc_cli::check() {
+            eprintln!("{e} Pass `--offline` to use the downloaded puzzle descriptions.");
+            process::exit(1);
+        }
+    }
+
+    let part_count = current_year().part_count();
+    let mut answers = Answers::read_from_file();
+
+    let is_selected = days.is_some();
+    let selected = days.unwrap_or_else(DaySpec::all).resolve_from_files();
+    let days_to_harvest: Vec<Day> = sorted_days(&selected)
+        .filter(|day| paths::day_module_path(*day).exists())
+        .filter(|day| is_selected || !answers.is_day_complete(*day))
+        .collect();
+
+    if days_to_harvest.is_empty() {
+        println!("No days to harvest, the answers of all scaffolded days are known.");
+        return;
+    }
+
+    let mut changed = false;
+
+    for day in days_to_harvest {
+        if !offline {
+            if let Err(e) = aoc_cli::download_puzzle(day) {
+                eprintln!("Day {day}: failed to download the puzzle description: {e}");
+                continue;
+            }
+        }
+
+        let Ok(puzzle) = read_puzzle(day) else {
+            eprintln!(
+                "Day {day}: \"{}\" does not exist.",
+                puzzle_path(day).display()
+            );
+            continue;
+        };
+
+        let harvested = find_puzzle_answers(&puzzle);
+        if harvested.is_empty() {
+            if !output::is_quiet() {
+                println!("Day {day}: no solved parts.");
+            }
+            continue;
+        }
+
+        let mut day_changed = false;
+
+        for (part, answer) in (1..=part_count).zip(&harvested) {
+            match answers.get(day, part) {
+                Some(known) if known == answer => {}
+                Some(known) => {
+                    println!(
+                        "Day {day}: replaced answer of part {part} ({known} -> {})",
+                        bold(answer)
+                    );
+                    answers.set(day, part, answer);
+                    day_changed = true;
+                }
+                None => {
+                    if !output::is_quiet() {
+                        println!("Day {day}: part {part} is {}", bold(answer));
+                    }
+                    answers.set(day, part, answer);
+                    day_changed = true;
+                }
+            }
+        }
+
+        if !day_changed && !output::is_quiet() {
+            println!("Day {day}: answers are up to date.");
+        }
+
+        changed |= day_changed;
+    }
+
+    if changed {
+        if let Err(e) = answers.store_file() {
+            eprintln!("Failed to store answers: {e}");
+            process::exit(1);
+        }
+
+        if !output::is_quiet() {
+            println!("---");
+            println!(
+                "🎄 Stored the harvested answers in \"{}\".",
+                paths::data_file("answers.json").display()
+            );
+        }
+    }
+}
@@ -1,6 +1,12 @@
 pub mod all;
+pub mod check_examples;
+pub mod compare;
 pub mod download;
+pub mod extract_examples;
+pub mod harvest_answers;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
 pub mod time;
+#[cfg(feature = "today")]
+pub mod today;
@@ -1,26 +1,201 @@
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::Path,
     process,
 };
 
-use crate::template::Day;
+use crate::template::{all_days, current_year, output, paths, Day, Year, PART_NAMES};
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
+/// Arguments of `solution!` for a day. Days of events with two parts rely on the default parts,
+/// others list their parts, e.g. `1, parts: [part_one, part_two, part_three]`.
+fn solution_args(year: Year, day: Day) -> String {
+    let part_count = usize::from(year.part_count());
+    if part_count == 2 {
+        return day.into_inner().to_string();
+    }
+
+    format!(
+        "{}, parts: [{}]",
+        day.into_inner(),
+        PART_NAMES[..part_count].join(", ")
+    )
+}
+
+/// The library module of a day, with a stub and an example test for every part.
+fn module_contents(year: Year, day: Day) -> String {
+    let parts = &PART_NAMES[..usize::from(year.part_count())];
+
+    MODULE_TEMPLATE
+        .replace("%SOLUTION%", &solution_args(year, day))
+        .replace(
+            "%PARTS%",
+            &parts
+                .iter()
+                .map(|part| {
+                    format!("\npub fn {part}(input: &str) -> Option<u32> {{\n    None\n}}\n")
+                })
+                .collect::<String>(),
+        )
+        .replace(
+            "%EXAMPLES%",
+            &parts
+                .iter()
+                .map(|part| format!("    {part}: \"{day}\" => None,\n"))
+                .collect::<String>(),
+        )
+}
+
+/// The binary of a day only runs the solution of its library module.
+fn bin_contents(year: Year, day: Day) -> String {
+    format!(
+        "use advent_of_code::days::{}::day{day}::*;\n\nadvent_of_code::solution!({});\n",
+        year.module_name(),
+        solution_args(year, day)
+    )
+}
+
+fn safe_create_file(path: &Path) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+fn create_file(path: &Path) -> Result<File, std::io::Error> {
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+}
+
+/// Lines of a constant slice, laid out the way `rustfmt` does: on a single line if it is short
+/// enough, one item per line otherwise.
+fn const_list(declaration: &str, items: impl Iterator<Item = String>) -> Vec<String> {
+    let items: Vec<String> = items.collect();
+    let single_line = format!("[{}]", items.join(", "));
+
+    if single_line.len() <= 60 {
+        return vec![format!("{declaration}&{single_line};")];
+    }
+
+    let mut lines = vec![format!("{declaration}&[")];
+    lines.extend(items.iter().map(|item| format!("    {item},")));
+    lines.push("];".into());
+    lines
+}
+
+/// The `year!` invocation that creates a year, e.g. `crate::year!(ec 2024)`.
+fn year_macro(year: Year) -> String {
+    match year.event().prefix() {
+        "" => format!("crate::year!({year})"),
+        prefix => format!("crate::year!({prefix} {})", year.into_inner()),
+    }
+}
+
+/// Contents of the module of a year, e.g. `src/days/y2023/mod.rs`, which declares the module of
+/// every day and collects their solutions into `SOLUTIONS`.
+fn year_module(year: Year, days: &[Day]) -> String {
+    let mut lines = vec![
+        format!(
+            "// Generated by `cargo scaffold`, every day of {} is a module of the library.",
+            year.title()
+        ),
+        "use crate::template::{registry::Solution, Year};".into(),
+        String::new(),
+        "/// The year of the days in this module.".into(),
+        format!("pub const YEAR: Year = {};", year_macro(year)),
+        String::new(),
+    ];
+
+    lines.extend(days.iter().map(|day| format!("pub mod day{day};")));
+    lines.push(String::new());
+    lines.push(format!(
+        "/// The solutions of all days of {}, in order.",
+        year.title()
+    ));
+    lines.extend(const_list(
+        "pub const SOLUTIONS: &[Solution] = ",
+        days.iter().map(|day| format!("day{day}::SOLUTION")),
+    ));
+
+    lines.join("\n") + "\n"
+}
+
+/// Contents of `src/days/mod.rs`, which declares the module of every year and collects their
+/// solutions into `SOLUTIONS`.
+fn days_module(years: &[Year]) -> String {
+    let mut lines = vec![
+        "// Generated by `cargo scaffold`, every year is a module of the library.".to_string(),
+        "use crate::template::registry::Solution;".into(),
+        String::new(),
+    ];
+
+    // `rustfmt` sorts module declarations by name.
+    let mut modules: Vec<String> = years.iter().map(|year| year.module_name()).collect();
+    modules.sort();
+
+    lines.extend(modules.iter().map(|module| format!("pub mod {module};")));
+    lines.push(String::new());
+    lines.push("/// The solutions of all years, in order.".into());
+    lines.extend(const_list(
+        "pub const SOLUTIONS: &[&[Solution]] = ",
+        years
+            .iter()
+            .map(|year| format!("{}::SOLUTIONS", year.module_name())),
+    ));
+
+    lines.join("\n") + "\n"
+}
+
+/// The years that have a module in `src/days`, in order.
+fn module_years() -> io::Result<Vec<Year>> {
+    let mut years: Vec<Year> = fs::read_dir(paths::root().join("src").join("days"))?
+        .filter_map(Result::ok)
+        .filter_map(|entry| Year::from_module_name(entry.file_name().to_str()?))
+        .filter(|year| paths::year_module_dir(*year).join("mod.rs").exists())
+        .collect();
+
+    years.sort();
+    Ok(years)
+}
+
+/// Regenerate the module of the current year and `src/days/mod.rs` from the day modules that exist.
+fn register_module() -> io::Result<()> {
+    let year = current_year();
+    let days: Vec<Day> = all_days()
+        .filter(|day| paths::day_module_path(*day).exists())
+        .collect();
+
+    fs::write(
+        paths::year_module_dir(year).join("mod.rs"),
+        year_module(year, &days),
+    )?;
+    fs::write(paths::days_mod_path(), days_module(&module_years()?))
+}
+
+/// Create the folders of the current year, which do not exist yet in a new year.
+fn create_year_dirs() -> io::Result<()> {
+    fs::create_dir_all(paths::year_module_dir(current_year()))?;
+
+    for folder in ["inputs", "examples", "puzzles"] {
+        fs::create_dir_all(paths::data_dir(folder))?;
+    }
+
+    Ok(())
 }
 
 pub fn handle(day: Day) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+    let input_path = paths::data_dir("inputs").join(format!("{day}.txt"));
+    let example_path = paths::data_dir("examples").join(format!("{day}.txt"));
+    let module_path = paths::day_module_path(day);
+    let bin_path = paths::bin_path(day);
+
+    if let Err(e) = create_year_dirs() {
+        eprintln!("Failed to create the folders of {}: {e}", current_year());
+        process::exit(1);
+    }
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -30,13 +205,11 @@ pub fn handle(day: Day) {
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    match file.write_all(module_contents(current_year(), day).as_bytes()) {
         Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
+            if !output::is_quiet() {
+                println!("Created module file \"{}\"", module_path.display());
+            }
         }
         Err(e) => {
             eprintln!("Failed to write module contents: {e}");
@@ -44,9 +217,40 @@ pub fn handle(day: Day) {
         }
     }
 
+    match register_module() {
+        Ok(()) => {
+            if !output::is_quiet() {
+                println!(
+                    "Registered module in \"{}\"",
+                    paths::days_mod_path().display()
+                );
+            }
+        }
+        Err(e) => {
+            eprintln!("Failed to register module: {e}");
+            process::exit(1);
+        }
+    }
+
+    match safe_create_file(&bin_path)
+        .and_then(|mut file| file.write_all(bin_contents(current_year(), day).as_bytes()))
+    {
+        Ok(()) => {
+            if !output::is_quiet() {
+                println!("Created binary file \"{}\"", bin_path.display());
+            }
+        }
+        Err(e) => {
+            eprintln!("Failed to create binary file: {e}");
+            process::exit(1);
+        }
+    }
+
     match create_file(&input_path) {
         Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+            if !output::is_quiet() {
+                println!("Created empty input file \"{}\"", input_path.display());
+            }
         }
         Err(e) => {
             eprintln!("Failed to create input file: {e}");
@@ -56,7 +260,9 @@ pub fn handle(day: Day) {
 
     match create_file(&example_path) {
         Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+            if !output::is_quiet() {
+                println!("Created empty example file \"{}\"", example_path.display());
+            }
         }
         Err(e) => {
             eprintln!("Failed to create example file: {e}");
@@ -64,6 +270,103 @@ pub fn handle(day: Day) {
         }
     }
 
-    println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    if !output::is_quiet() {
+        println!("---");
+        println!("🎄 Type `cargo solve {day}` to run your solution.");
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{bin_contents, days_module, module_contents, year_module};
+    use crate::{day, year};
+
+    #[test]
+    fn generates_year_module() {
+        let expected = [
+            "// Generated by `cargo scaffold`, every day of 2023 is a module of the library.",
+            "use crate::template::{registry::Solution, Year};",
+            "",
+            "/// The year of the days in this module.",
+            "pub const YEAR: Year = crate::year!(2023);",
+            "",
+            "pub mod day01;",
+            "pub mod day03;",
+            "",
+            "/// The solutions of all days of 2023, in order.",
+            "pub const SOLUTIONS: &[Solution] = &[day01::SOLUTION, day03::SOLUTION];",
+            "",
+        ]
+        .join("\n");
+
+        assert_eq!(year_module(year!(2023), &[day!(1), day!(3)]), expected);
+    }
+
+    #[test]
+    fn generates_days_module() {
+        let expected = [
+            "// Generated by `cargo scaffold`, every year is a module of the library.",
+            "use crate::template::registry::Solution;",
+            "",
+            "pub mod y2022;",
+            "pub mod y2023;",
+            "",
+            "/// The solutions of all years, in order.",
+            "pub const SOLUTIONS: &[&[Solution]] = &[y2022::SOLUTIONS, y2023::SOLUTIONS];",
+            "",
+        ]
+        .join("\n");
+
+        assert_eq!(days_module(&[year!(2022), year!(2023)]), expected);
+    }
+
+    #[test]
+    fn generates_modules_of_other_events() {
+        let module = year_module(year!(ec 2024), &[day!(1)]);
+        assert!(module.contains("pub const YEAR: Year = crate::year!(ec 2024);"));
+
+        let module = days_module(&[year!(2023), year!(ec 2024)]);
+        assert!(module.contains("pub mod ec2024;\npub mod y2023;"));
+        assert!(module.contains("&[y2023::SOLUTIONS, ec2024::SOLUTIONS]"));
+    }
+
+    #[test]
+    fn generates_a_stub_for_every_part() {
+        let module = module_contents(year!(2023), day!(1));
+        assert!(module.starts_with("crate::solution!(lib 1);\n"));
+        assert!(module.contains("pub fn part_two(input: &str)"));
+        assert!(!module.contains("part_three"));
+
+        let module = module_contents(year!(ec 2024), day!(3));
+        assert!(module
+            .starts_with("crate::solution!(lib 3, parts: [part_one, part_two, part_three]);\n"));
+        assert!(module.contains("pub fn part_three(input: &str) -> Option<u32> {\n    None\n}\n"));
+        assert!(module.contains("    part_three: \"03\" => None,\n}"));
+
+        assert_eq!(
+            bin_contents(year!(ec 2024), day!(3)),
+            "use advent_of_code::days::ec2024::day03::*;\n\nadvent_of_code::solution!(3, parts: [part_one, part_two, part_three]);\n"
+        );
+    }
+
+    #[test]
+    fn wraps_long_solution_lists() {
+        let days = [day!(1), day!(2), day!(3), day!(4)];
+        let module = year_module(year!(2023), &days);
+
+        assert!(module.ends_with(
+            &[
+                "pub const SOLUTIONS: &[Solution] = &[",
+                "    day01::SOLUTION,",
+                "    day02::SOLUTION,",
+                "    day03::SOLUTION,",
+                "    day04::SOLUTION,",
+                "];",
+                "",
+            ]
+            .join("\n")
+        ));
+    }
 }
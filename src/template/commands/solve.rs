@@ -1,9 +1,16 @@
-use std::process::{Command, Stdio};
+use std::process::{self, Command, ExitStatus, Stdio};
 
-use crate::template::Day;
+use crate::template::{
+    aoc_cli, current_year, output, paths, scheduling::SchedulingOptions, Day, PART_NAMES,
+};
 
-pub fn handle(day: Day, release: bool, time: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+/// Arguments of `cargo run` for the binary of a day, up to and including the `--` separator.
+fn cargo_run_args(day: Day, release: bool, dhat: bool) -> Vec<String> {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), paths::bin_name(day)];
+
+    if output::is_quiet() {
+        cmd_args.push("--quiet".to_string());
+    }
 
     if dhat {
         cmd_args.extend([
@@ -16,23 +23,110 @@ pub fn handle(day: Day, release: bool, time: bool, dhat: bool, submit_part: Opti
         cmd_args.push("--release".to_string());
     }
 
+    // mirror the `embed-inputs` feature this binary was built with.
+    if cfg!(feature = "embed-inputs") {
+        cmd_args.extend(["--features".to_string(), "embed-inputs".to_string()]);
+    }
+
     cmd_args.push("--".to_string());
+    cmd_args
+}
 
-    if let Some(submit_part) = submit_part {
-        cmd_args.push("--submit".to_string());
-        cmd_args.push(submit_part.to_string());
+/// A part to submit after solving. Unless `force` is set, the part is only submitted if the
+/// example tests of the day pass.
+pub struct Submission {
+    pub part: u8,
+    pub force: bool,
+}
+
+/// Run the tests of a day, leaving out the tests of the other parts, e.g. `part_two` when
+/// submitting part one. Tests of other functions, e.g. a parser, are run as well.
+fn run_example_tests(day: Day, part: u8) -> bool {
+    let mut cmd_args = vec!["test".to_string()];
+    if paths::day_module_path(day).exists() {
+        let filter = format!("days::{}::day{day}::", current_year().module_name());
+        cmd_args.extend(["--lib".to_string(), filter]);
+    } else {
+        cmd_args.extend(["--bin".to_string(), paths::bin_name(day)]);
     }
 
-    if time {
-        cmd_args.push("--time".to_string());
+    cmd_args.push("--".to_string());
+    for (other_part, name) in (1..).zip(PART_NAMES) {
+        if other_part != part {
+            cmd_args.extend(["--skip".to_string(), name.into()]);
+        }
     }
 
+    if !output::is_quiet() {
+        println!("Running example tests of day {day} before submitting part {part}...");
+    }
+
+    run_cargo(&cmd_args).success()
+}
+
+fn run_cargo(cmd_args: &[String]) -> ExitStatus {
     let mut cmd = Command::new("cargo")
-        .args(&cmd_args)
+        .current_dir(paths::root())
+        .args(cmd_args)
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
         .spawn()
         .unwrap();
 
-    cmd.wait().unwrap();
+    cmd.wait().unwrap()
+}
+
+pub fn handle(
+    day: Day,
+    release: bool,
+    time: bool,
+    dhat: bool,
+    submit: Option<Submission>,
+    variants: bool,
+    scheduling: &SchedulingOptions,
+) {
+    let mut cmd_args = cargo_run_args(day, release, dhat);
+
+    if let Some(Submission { part, force }) = submit {
+        // refuse before building and running the example tests.
+        if let Err(e) = aoc_cli::check_unlocked(day) {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+
+        if !force && !run_example_tests(day, part) {
+            eprintln!(
+                "Example tests of day {day} failed, part {part} was not submitted. Pass `--force` to submit anyway."
+            );
+            process::exit(1);
+        }
+
+        cmd_args.push("--submit".to_string());
+        cmd_args.push(part.to_string());
+    }
+
+    if time {
+        cmd_args.push("--time".to_string());
+    }
+
+    if variants {
+        cmd_args.push("--variants".to_string());
+    }
+
+    if time || variants {
+        cmd_args.extend(scheduling.to_args());
+    }
+
+    run_cargo(&cmd_args);
+}
+
+/// Run the solution of a day against its examples instead of the puzzle input.
+/// Exits with an error if an answer differs from the one listed in `data/<year>/examples.json`.
+pub fn handle_examples(day: Day, release: bool) {
+    let mut cmd_args = cargo_run_args(day, release, false);
+    cmd_args.push("--example".to_string());
+
+    if !run_cargo(&cmd_args).success() {
+        process::exit(1);
+    }
 }
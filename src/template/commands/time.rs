@@ -1,38 +1,134 @@
 use std::collections::HashSet;
 
+use crate::template::answers::Answers;
+use crate::template::comparison::print_comparison;
+use crate::template::profiles::Profile;
 use crate::template::run_multi::run_multi;
+use crate::template::scheduling::SchedulingOptions;
+use crate::template::selection::DaySpec;
 use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::{
+    all_days, bench_reports, current_year,
+    output::{self, bold},
+    readme_benchmarks, Day,
+};
+
+pub fn handle(
+    days: Option<DaySpec>,
+    recreate_all: bool,
+    criterion: bool,
+    profiles: Option<Vec<Profile>>,
+    store: bool,
+    scheduling: &SchedulingOptions,
+) {
+    if let Some(profiles) = profiles {
+        handle_profiles(days, &profiles, store, scheduling);
+        return;
+    }
+
+    if criterion {
+        import_bench_reports(days);
+        return;
+    }
 
-pub fn handle(day: Option<Day>, recreate_all: bool) {
     let stored_timings = Timings::read_from_file();
 
-    let days_to_run = day.map_or_else(
-        || {
-            if recreate_all {
-                all_days().collect()
-            } else {
-                // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
-                    .collect()
-            }
-        },
-        |day| HashSet::from([day]),
-    );
+    let days_to_run = match days {
+        Some(days) => days.resolve(&stored_timings, &Answers::read_from_file()),
+        None if recreate_all => all_days().collect(),
+        // when the `--all` flag is not set, filter out days that are fully benched.
+        None => all_days()
+            .filter(|day| !stored_timings.is_day_complete(*day))
+            .collect(),
+    };
+
+    let timings = run_multi(&days_to_run, &Profile::release(), true, scheduling)
+        .timings
+        .unwrap();
+    store_timings(&Profile::release(), &stored_timings, &timings);
+}
+
+/// Bench the selected days once per profile and print a comparison table.
+/// Timings are only persisted if `store` is set.
+fn handle_profiles(
+    days: Option<DaySpec>,
+    profiles: &[Profile],
+    store: bool,
+    scheduling: &SchedulingOptions,
+) {
+    // a comparison is only meaningful on the same set of days, so all days are run by default.
+    let days_to_run: HashSet<Day> = days.unwrap_or_else(DaySpec::all).resolve_from_files();
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let mut columns: Vec<(String, Timings)> = Vec::with_capacity(profiles.len());
+
+    for profile in profiles {
+        println!("{}", bold(format!("Profile: {profile}")));
+        println!("==========");
+        let timings = run_multi(&days_to_run, profile, true, scheduling)
+            .timings
+            .unwrap();
+
+        if store {
+            store_timings(profile, &Timings::read_from_file_for(profile), &timings);
+        }
+
+        println!();
+        columns.push((profile.to_string(), timings));
+    }
+
+    print_comparison(&columns);
+}
 
-    let merged_timings = stored_timings.merge(&timings);
-    merged_timings.store_file().unwrap();
+/// Store the timings of the reports written by `cargo bench` instead of running the solutions.
+fn import_bench_reports(days: Option<DaySpec>) {
+    let days_to_import = days.unwrap_or_else(DaySpec::all).resolve_from_files();
+    let timings = bench_reports::read_timings(&days_to_import);
+
+    if timings.data.is_empty() {
+        eprintln!("No bench reports found. Run `cargo bench` first.");
+        std::process::exit(1);
+    }
+
+    if !output::is_quiet() {
+        let year = current_year();
+
+        for timing in &timings.data {
+            let parts: Vec<String> = (1..=year.part_count())
+                .map(|part| format!("part {part} {}", timing.part(part).unwrap_or("-")))
+                .collect();
+
+            println!(
+                "{} {}: {}",
+                year.event().day_label(),
+                timing.day,
+                parts.join(", ")
+            );
+        }
+    }
+
+    store_timings(&Profile::release(), &Timings::read_from_file(), &timings);
+}
+
+fn store_timings(profile: &Profile, stored_timings: &Timings, timings: &Timings) {
+    let merged_timings = stored_timings.merge(timings);
+    merged_timings.store_file_for(profile).unwrap();
+
+    // the readme only shows timings of the default profile.
+    if !profile.is_default() {
+        if !output::is_quiet() {
+            println!("Stored updated benchmarks for profile `{profile}`.");
+        }
+        return;
+    }
 
-    println!();
     match readme_benchmarks::update(merged_timings) {
         Ok(()) => {
-            println!("Stored updated benchmarks.");
+            if !output::is_quiet() {
+                println!("\nStored updated benchmarks.");
+            }
         }
-        Err(_) => {
-            eprintln!("Failed to store updated benchmarks.");
+        Err(e) => {
+            eprintln!("Failed to store updated benchmarks: {e}");
         }
     }
 }
@@ -0,0 +1,161 @@
+use std::{
+    io::{stdout, Write},
+    process,
+    thread::sleep,
+    time::Duration,
+};
+
+use chrono::{DateTime, Utc};
+
+use crate::template::commands::{read, scaffold};
+use crate::template::{aoc_cli, output, paths, set_year, Day, Year};
+
+/// How often the download is attempted after a puzzle unlocked, the puzzle might not be
+/// available in the first seconds.
+const DOWNLOAD_ATTEMPTS: u32 = 5;
+
+/// Delay between two download attempts.
+const RETRY_DELAY: Duration = Duration::from_secs(3);
+
+/// Scaffold, download and read the puzzle of today.
+///
+/// With `wait`, the command waits for the next puzzle to unlock first, unless the puzzle of today
+/// is unlocked but not scaffolded yet.
+pub fn handle(wait: bool) {
+    if let Some(day) = Day::today() {
+        // today's puzzle belongs to the event of the current year.
+        set_year(Year::today());
+
+        if !wait || !paths::day_module_path(day).exists() {
+            fetch(day, false);
+            return;
+        }
+    }
+
+    if !wait {
+        eprintln!(
+            "`today` command can only be run on a day of this year's event in december. \
+            Please use `scaffold` with a specific day, or pass `--wait` to wait for the next puzzle."
+        );
+        process::exit(1);
+    }
+
+    let Some((day, year)) = Day::next_unlock(Utc::now()) else {
+        eprintln!("Could not determine the next puzzle to unlock.");
+        process::exit(1);
+    };
+
+    set_year(year);
+
+    if let Err(e) = aoc_cli::check() {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+
+    let Some(unlock_time) = day.unlock_time(year) else {
+        eprintln!("Day {day} of {year} has no unlock time.");
+        process::exit(1);
+    };
+
+    wait_until(unlock_time.into(), &format!("Day {day} of {year}"));
+    fetch(day, true);
+}
+
+/// Scaffold a day unless it exists, then download and print its puzzle. `retry` retries the
+/// download, for puzzles that just unlocked.
+fn fetch(day: Day, retry: bool) {
+    if !paths::day_module_path(day).exists() {
+        scaffold::handle(day);
+    }
+
+    download(day, if retry { DOWNLOAD_ATTEMPTS } else { 1 });
+    read::handle(day);
+}
+
+fn download(day: Day, attempts: u32) {
+    if let Err(e) = aoc_cli::check() {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+
+    for attempt in 1..=attempts {
+        match aoc_cli::download(day) {
+            Ok(_) => return,
+            Err(e) if attempt < attempts => {
+                eprintln!("failed to call aoc-cli: {e} Retrying in {RETRY_DELAY:?}...");
+                sleep(RETRY_DELAY);
+            }
+            Err(e) => {
+                eprintln!("failed to call aoc-cli: {e}");
+                process::exit(1);
+            }
+        }
+    }
+}
+
+/// Block until `time`, showing a countdown in terminals.
+fn wait_until(time: DateTime<Utc>, puzzle: &str) {
+    if !output::is_quiet() {
+        println!(
+            "{puzzle} unlocks at {} in your timezone.",
+            time.with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M:%S")
+        );
+    }
+
+    while let Ok(remaining) = (time - Utc::now()).to_std() {
+        if remaining.is_zero() {
+            break;
+        }
+
+        if output::is_terminal() && !output::is_quiet() {
+            print!("\r⏳ {} ", format_countdown(remaining));
+            let _ = stdout().flush();
+            sleep(remaining.min(Duration::from_secs(1)));
+        } else {
+            sleep(remaining);
+        }
+    }
+
+    if output::is_terminal() && !output::is_quiet() {
+        println!("\r🎄 Unlocked!          ");
+    }
+}
+
+/// Format a remaining duration, e.g. `2d 03:04:05` or `00:12:30`.
+fn format_countdown(remaining: Duration) -> String {
+    // round up, so that the countdown reaches zero when the puzzle unlocks.
+    let seconds = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+    let (days, seconds) = (seconds / 86_400, seconds % 86_400);
+    let clock = format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    );
+
+    if days > 0 {
+        format!("{days}d {clock}")
+    } else {
+        clock
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::format_countdown;
+
+    #[test]
+    fn formats_countdowns() {
+        assert_eq!(format_countdown(Duration::from_millis(12_500)), "00:00:13");
+        assert_eq!(format_countdown(Duration::from_secs(3_725)), "01:02:05");
+        assert_eq!(
+            format_countdown(Duration::from_secs(180_000)),
+            "2d 02:00:00"
+        );
+    }
+}
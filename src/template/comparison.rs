@@ -0,0 +1,141 @@
+/// Renders side-by-side comparisons of several sets of timings, e.g. one per build profile.
+use crate::template::run_multi::child_commands::parse_duration;
+use crate::template::timings::Timings;
+use crate::template::{current_year, output::bold, Day};
+
+/// Print one comparison table per part of the current year.
+/// The first column is the baseline that all other columns are compared against.
+pub fn print_comparison(columns: &[(String, Timings)]) {
+    for part in 1..=current_year().part_count() {
+        println!();
+        println!("{}", bold(format!("Part {part}")));
+        println!("{}", format_comparison(columns, part));
+    }
+}
+
+fn format_cell(time: Option<&str>, baseline: Option<f64>) -> String {
+    let Some(time) = time else {
+        return "-".into();
+    };
+
+    match (parse_duration(time), baseline) {
+        (Some(nanos), Some(baseline)) if baseline > 0.0 => {
+            format!("{time} ({:+.1}%)", (nanos - baseline) / baseline * 100.0)
+        }
+        _ => time.into(),
+    }
+}
+
+fn format_comparison(columns: &[(String, Timings)], part: u8) -> String {
+    let mut days: Vec<Day> = columns
+        .iter()
+        .flat_map(|(_, timings)| timings.data.iter().map(|t| t.day))
+        .collect();
+    days.sort_unstable();
+    days.dedup();
+
+    let mut rows: Vec<Vec<String>> = vec![std::iter::once("Day".to_string())
+        .chain(columns.iter().map(|(name, _)| name.clone()))
+        .collect()];
+
+    for day in days {
+        let times: Vec<Option<&str>> = columns
+            .iter()
+            .map(|(_, timings)| {
+                timings
+                    .data
+                    .iter()
+                    .find(|t| t.day == day)
+                    .and_then(|t| t.part(part))
+            })
+            .collect();
+
+        let baseline = times.first().copied().flatten().and_then(parse_duration);
+
+        let mut row = vec![day.to_string()];
+        for (i, time) in times.into_iter().enumerate() {
+            row.push(if i == 0 {
+                format_cell(time, None)
+            } else {
+                format_cell(time, baseline)
+            });
+        }
+        rows.push(row);
+    }
+
+    let widths: Vec<usize> = (0..=columns.len())
+        .map(|i| {
+            rows.iter()
+                .map(|row| row[i].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    rows.iter()
+        .map(|row| {
+            row.iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect::<Vec<_>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::format_comparison;
+    use crate::{
+        day,
+        template::timings::{Timing, Timings},
+    };
+
+    fn get_mock_timings(part_1: &str, part_2: Option<&str>) -> Timings {
+        Timings {
+            data: vec![Timing {
+                day: day!(1),
+                parts: vec![Some(part_1.into()), part_2.map(Into::into)],
+                total_nanos: 0_f64,
+                scheduling: None,
+            }],
+        }
+    }
+
+    #[test]
+    fn formats_relative_timings() {
+        let columns = vec![
+            (
+                "release".to_string(),
+                get_mock_timings("10.0ms", Some("1.0ms")),
+            ),
+            ("native".to_string(), get_mock_timings("7.5ms", None)),
+        ];
+
+        assert_eq!(
+            format_comparison(&columns, 1),
+            ["Day  release  native", "01   10.0ms   7.5ms (-25.0%)"].join("\n")
+        );
+
+        assert_eq!(
+            format_comparison(&columns, 2),
+            ["Day  release  native", "01   1.0ms    -"].join("\n")
+        );
+    }
+
+    #[test]
+    fn handles_missing_baseline() {
+        let columns = vec![
+            ("release".to_string(), Timings::default()),
+            ("native".to_string(), get_mock_timings("7.5ms", None)),
+        ];
+
+        assert_eq!(
+            format_comparison(&columns, 1),
+            ["Day  release  native", "01   -        7.5ms"].join("\n")
+        );
+    }
+}
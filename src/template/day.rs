@@ -1,11 +1,22 @@
+use std::collections::HashSet;
 use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+use crate::template::{current_year, Event, Year};
+
+#[cfg(feature = "today")]
+use chrono::{DateTime, Datelike, FixedOffset, Utc};
+
+/// Puzzles of Advent of Code unlock at midnight EST (UTC-5), regardless of the local timezone.
 #[cfg(feature = "today")]
-use chrono::{Datelike, Local};
+pub fn unlock_timezone() -> FixedOffset {
+    FixedOffset::west_opt(5 * 60 * 60).expect("offset is in range")
+}
 
-/// A valid day number of advent (i.e. an integer in range 1 to 25).
+/// A valid day number of advent, i.e. an integer in range 1 to the last day of the event of the
+/// current year (25 until 2024, 12 from 2025 on), see [`Year::day_count`].
 ///
 /// # Display
 /// This value displays as a two digit number.
@@ -19,10 +30,16 @@ use chrono::{Datelike, Local};
 pub struct Day(u8);
 
 impl Day {
-    /// Creates a [`Day`] from the provided value if it's in the valid range,
+    /// Creates a [`Day`] from the provided value if it's a day of the event of the current year,
     /// returns [`None`] otherwise.
     pub fn new(day: u8) -> Option<Self> {
-        if day == 0 || day > 25 {
+        Self::new_in(day, current_year())
+    }
+
+    /// Creates a [`Day`] from the provided value if it's a day of the event of a year,
+    /// returns [`None`] otherwise.
+    pub fn new_in(day: u8, year: Year) -> Option<Self> {
+        if day == 0 || day > year.day_count() {
             return None;
         }
         Some(Self(day))
@@ -40,16 +57,66 @@ impl Day {
     }
 }
 
+impl Day {
+    /// The moment the puzzle of this day of a year unlocks, i.e. midnight EST (05:00 UTC) on that
+    /// day of december. `None` for events without a fixed schedule.
+    pub fn unlock_time(self, year: Year) -> Option<SystemTime> {
+        if year.event() != Event::AdventOfCode {
+            return None;
+        }
+
+        // leap days from the year 1 up to and including a year.
+        let leap_days = |year: u64| year / 4 - year / 100 + year / 400;
+        let year = u64::from(year.into_inner());
+        let is_leap_year = leap_days(year) - leap_days(year - 1);
+
+        // days from the epoch to the 1st of january, then to the 1st of december.
+        let days = (year - 1970) * 365 + leap_days(year - 1) - leap_days(1969);
+        let days = days + 334 + is_leap_year + u64::from(self.0) - 1;
+
+        Some(UNIX_EPOCH + Duration::from_secs((days * 24 + 5) * 60 * 60))
+    }
+
+    /// Whether the puzzle of this day of the current year is unlocked. Days of events without a
+    /// fixed schedule are always considered unlocked.
+    pub fn is_unlocked(self) -> bool {
+        self.unlock_time(current_year())
+            .is_none_or(|time| SystemTime::now() >= time)
+    }
+}
+
 #[cfg(feature = "today")]
 impl Day {
-    /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
+    /// Returns the day whose puzzle unlocked last if it's a day of this year's event in december,
+    /// e.g. between the 1st and the 12th of december 2025, `None` otherwise. Days start at the
+    /// unlock time in EST, see [`unlock_timezone`].
     pub fn today() -> Option<Self> {
-        let today = Local::now();
-        if today.month() == 12 && today.day() <= 25 {
-            Self::new(u8::try_from(today.day()).ok()?)
-        } else {
-            None
+        Self::today_at(Utc::now())
+    }
+
+    fn today_at(now: DateTime<Utc>) -> Option<Self> {
+        let now = now.with_timezone(&unlock_timezone());
+        if now.month() != 12 {
+            return None;
         }
+
+        let year = Year::new(u16::try_from(now.year()).ok()?)?;
+        Self::new_in(u8::try_from(now.day()).ok()?, year)
+    }
+
+    /// The first day of advent of code that unlocks after `now` and the year it belongs to,
+    /// e.g. day 1 of the next event once the last day of an event unlocked.
+    pub fn next_unlock(now: DateTime<Utc>) -> Option<(Self, Year)> {
+        let year = u16::try_from(now.with_timezone(&unlock_timezone()).year()).ok()?;
+
+        [year, year + 1]
+            .into_iter()
+            .filter_map(Year::new)
+            .flat_map(|year| AllDays::of(year).map(move |day| (day, year)))
+            .find(|(day, year)| {
+                day.unlock_time(*year)
+                    .is_some_and(|time| DateTime::<Utc>::from(time) > now)
+            })
     }
 }
 
@@ -77,39 +144,56 @@ impl FromStr for Day {
     type Err = DayFromStrError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let day = s.parse().map_err(|_| DayFromStrError)?;
-        Self::new(day).ok_or(DayFromStrError)
+        let year = current_year();
+        let error = DayFromStrError {
+            day_count: year.day_count(),
+        };
+
+        let day = s.parse().map_err(|_| error)?;
+        Self::new_in(day, year).ok_or(error)
     }
 }
 
 /// An error which can be returned when parsing a [`Day`].
-#[derive(Debug)]
-pub struct DayFromStrError;
+#[derive(Debug, Clone, Copy)]
+pub struct DayFromStrError {
+    day_count: u8,
+}
 
 impl Error for DayFromStrError {}
 
 impl Display for DayFromStrError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("expecting a day number between 1 and 25")
+        write!(f, "expecting a day number between 1 and {}", self.day_count)
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of the event of the current year, starting at the 1st.
 pub fn all_days() -> AllDays {
     AllDays::new()
 }
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of the event of a year, starting at the 1st.
 pub struct AllDays {
     current: u8,
+    day_count: u8,
 }
 
 impl AllDays {
+    /// Yields the days of the event of the current year.
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
-        Self { current: 1 }
+        Self::of(current_year())
+    }
+
+    /// Yields the days of the event of a year.
+    pub fn of(year: Year) -> Self {
+        Self {
+            current: 1,
+            day_count: year.day_count(),
+        }
     }
 }
 
@@ -117,10 +201,63 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.current > self.day_count {
+            return None;
+        }
+        // NOTE: the iterator starts at 1 and we have verified that the value is a day of the event.
+        let day = Day(self.current);
+        self.current += 1;
+
+        Some(day)
+    }
+}
+
+/// An iterator that yields the days contained in an arbitrary set of days in ascending order.
+pub fn sorted_days(days: &HashSet<Day>) -> impl Iterator<Item = Day> + '_ {
+    all_days().filter(|day| days.contains(day))
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An inclusive range of days that yields every day it contains.
+///
+/// Parses from range syntax, e.g. `1..5`, `..5` or `20..`. Unlike rust ranges, the end is
+/// always included, i.e. `1..5` contains day 5 just like `1..=5`.
+///
+/// ```
+/// # use advent_of_code::template::DayRange;
+/// let days: Vec<_> = "3..5".parse::<DayRange>().unwrap().map(|d| d.to_string()).collect();
+/// assert_eq!(days, vec!["03", "04", "05"])
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DayRange {
+    current: u8,
+    end: u8,
+}
+
+impl DayRange {
+    /// Creates a [`DayRange`] that yields all days from `start` up to and including `end`.
+    pub fn new(start: Day, end: Day) -> Self {
+        Self {
+            current: start.0,
+            end: end.0,
+        }
+    }
+
+    /// Whether the range contains a day.
+    pub fn contains(&self, day: Day) -> bool {
+        self.current <= day.0 && day.0 <= self.end
+    }
+}
+
+impl Iterator for DayRange {
+    type Item = Day;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current > self.end {
             return None;
         }
-        // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
+        // NOTE: both bounds are valid days, so all values in between are valid as well.
         let day = Day(self.current);
         self.current += 1;
 
@@ -128,9 +265,59 @@ impl Iterator for AllDays {
     }
 }
 
+impl FromStr for DayRange {
+    type Err = DayRangeFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = current_year();
+        let last = year.day_count();
+        let error = DayRangeFromStrError { day_count: last };
+
+        let parse_bound = |bound: &str, default: u8| -> Result<u8, DayRangeFromStrError> {
+            if bound.is_empty() {
+                Ok(default)
+            } else {
+                bound.parse().map_err(|_| error)
+            }
+        };
+
+        // selections name the last day to run, `..=` is accepted as an alias of `..`.
+        let Some((start, end)) = s.split_once("..=").or_else(|| s.split_once("..")) else {
+            return Err(error);
+        };
+        let (start, end) = (parse_bound(start, 1)?, parse_bound(end, last)?);
+
+        match (Day::new_in(start, year), Day::new_in(end, year)) {
+            (Some(start), Some(end)) if start <= end => Ok(Self::new(start, end)),
+            _ => Err(error),
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`DayRange`].
+#[derive(Debug, Clone, Copy)]
+pub struct DayRangeFromStrError {
+    day_count: u8,
+}
+
+impl Error for DayRangeFromStrError {}
+
+impl Display for DayRangeFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "expecting a non-empty range of days between 1 and {}, e.g. `1..5` for days 1 to 5",
+            self.day_count
+        )
+    }
+}
+
 /* -------------------------------------------------------------------------- */
 
 /// Creates a [`Day`] value in a const context.
+///
+/// `day!(13)` accepts any day of the longest events, i.e. 1 to 25. Pass a year to only accept the
+/// days of its event, e.g. `day!(13, in YEAR)` fails to compile for 2025.
 #[macro_export]
 macro_rules! day {
     ($day:expr) => {{
@@ -144,13 +331,74 @@ macro_rules! day {
         );
         $crate::template::Day::__new_unchecked($day)
     }};
+    ($day:expr, in $year:expr) => {{
+        const _ASSERT: () = assert!(
+            $day != 0 && $day <= $year.day_count(),
+            concat!(
+                "invalid day number `",
+                $day,
+                "`, the day does not exist in the event of the year"
+            ),
+        );
+        $crate::template::Day::__new_unchecked($day)
+    }};
 }
 
 /* -------------------------------------------------------------------------- */
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{all_days, Day};
+    #[cfg(feature = "today")]
+    mod unlock {
+        use chrono::{DateTime, TimeZone, Utc};
+
+        use crate::template::Day;
+        use crate::{day, year};
+
+        fn utc(month: u32, day: u32, hour: u32) -> DateTime<Utc> {
+            Utc.with_ymd_and_hms(2024, month, day, hour, 0, 0).unwrap()
+        }
+
+        #[test]
+        fn follows_the_day_in_est() {
+            // still the 2nd in EST, although it's the 3rd in europe and asia.
+            assert_eq!(Day::today_at(utc(12, 3, 4)), Some(day!(2)));
+            assert_eq!(Day::today_at(utc(12, 3, 5)), Some(day!(3)));
+            assert_eq!(Day::today_at(utc(12, 1, 4)), None);
+            assert_eq!(Day::today_at(utc(12, 26, 12)), None);
+        }
+
+        #[test]
+        fn finds_the_next_unlock() {
+            assert_eq!(
+                Day::next_unlock(utc(11, 20, 12)),
+                Some((day!(1), year!(2024)))
+            );
+            assert_eq!(
+                Day::next_unlock(utc(12, 3, 5)),
+                Some((day!(4), year!(2024)))
+            );
+            assert_eq!(
+                Day::next_unlock(utc(12, 25, 12)),
+                Some((day!(1), year!(2025)))
+            );
+        }
+    }
+
+    use super::{all_days, sorted_days, AllDays, Day, DayRange};
+    use crate::{day, year};
+    use std::collections::HashSet;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn unlocks_at_midnight_est() {
+        let unix = |secs| Some(UNIX_EPOCH + Duration::from_secs(secs));
+
+        assert_eq!(day!(3).unlock_time(year!(2024)), unix(1_733_202_000));
+        // leap year.
+        assert_eq!(day!(1).unlock_time(year!(2016)), unix(1_480_568_400));
+        assert_eq!(day!(3).unlock_time(year!(ec 2024)), None);
+    }
 
     #[test]
     fn all_days_iterator() {
@@ -183,6 +431,41 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn follows_the_days_of_the_event() {
+        assert_eq!(AllDays::of(year!(2024)).count(), 25);
+        assert_eq!(AllDays::of(year!(2025)).last(), Some(Day(12)));
+        assert_eq!(Day::new_in(12, year!(2025)), Some(Day(12)));
+        assert_eq!(Day::new_in(13, year!(2025)), None);
+        assert_eq!(Day::new_in(13, year!(2024)), Some(Day(13)));
+    }
+
+    #[test]
+    fn sorted_days_iterator() {
+        let days = HashSet::from([Day(7), Day(1), Day(3)]);
+        assert_eq!(
+            sorted_days(&days).collect::<Vec<_>>(),
+            vec![Day(1), Day(3), Day(7)]
+        );
+    }
+
+    #[test]
+    fn parses_day_ranges() {
+        let parse = |s: &str| s.parse::<DayRange>().map(|r| r.collect::<Vec<_>>());
+
+        assert_eq!(parse("1..3").unwrap(), vec![Day(1), Day(2), Day(3)]);
+        assert_eq!(parse("2..=4").unwrap(), vec![Day(2), Day(3), Day(4)]);
+        assert_eq!(parse("..2").unwrap(), vec![Day(1), Day(2)]);
+        assert_eq!(parse("..=2").unwrap(), vec![Day(1), Day(2)]);
+        assert_eq!(parse("24..").unwrap(), vec![Day(24), Day(25)]);
+        assert_eq!(parse("..").unwrap().len(), 25);
+        assert_eq!(parse("3..3").unwrap(), vec![Day(3)]);
+        assert!(parse("5..2").is_err());
+        assert!(parse("0..2").is_err());
+        assert!(parse("1..=26").is_err());
+        assert!(parse("1").is_err());
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -0,0 +1,132 @@
+use std::fmt::Display;
+
+/// A puzzle event, e.g. Advent of Code. The event defines how many days (or quests) a year has,
+/// how many parts a puzzle has and the backend that downloads puzzles and submits answers.
+///
+/// Every [`Year`](crate::template::Year) belongs to an event, see [`Year::event`](crate::template::Year::event).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Event {
+    AdventOfCode,
+    EverybodyCodes,
+}
+
+/// Backend that downloads puzzles and inputs and submits answers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    /// The `aoc` command-line tool of the aoc-cli crate.
+    AocCli,
+    /// Inputs are saved and answers are submitted by hand.
+    Manual,
+}
+
+impl Event {
+    /// All supported events.
+    pub const ALL: [Event; 2] = [Event::AdventOfCode, Event::EverybodyCodes];
+
+    /// The year of the first edition of the event.
+    pub const fn first_year(self) -> u16 {
+        match self {
+            Event::AdventOfCode => 2015,
+            Event::EverybodyCodes => 2024,
+        }
+    }
+
+    /// The number of days (or quests) of an edition of the event. Advent of Code has 25 days
+    /// until 2024 and 12 from 2025 on, Everybody Codes has 20 quests.
+    pub const fn day_count(self, year: u16) -> u8 {
+        match self {
+            Event::AdventOfCode if year < 2025 => 25,
+            Event::AdventOfCode => 12,
+            Event::EverybodyCodes => 20,
+        }
+    }
+
+    /// The number of parts of every puzzle of the event.
+    pub const fn part_count(self) -> u8 {
+        match self {
+            Event::AdventOfCode => 2,
+            Event::EverybodyCodes => 3,
+        }
+    }
+
+    /// The backend that downloads puzzles and submits answers of the event.
+    pub const fn backend(self) -> Backend {
+        match self {
+            Event::AdventOfCode => Backend::AocCli,
+            Event::EverybodyCodes => Backend::Manual,
+        }
+    }
+
+    /// What the event calls a puzzle, e.g. `Day` or `Quest`.
+    pub const fn day_label(self) -> &'static str {
+        match self {
+            Event::AdventOfCode => "Day",
+            Event::EverybodyCodes => "Quest",
+        }
+    }
+
+    /// Prefix of the years of the event in names and paths, e.g. `ec` in `data/ec2024`.
+    /// Years of Advent of Code have no prefix.
+    pub const fn prefix(self) -> &'static str {
+        match self {
+            Event::AdventOfCode => "",
+            Event::EverybodyCodes => "ec",
+        }
+    }
+
+    /// Link to the puzzle of a day of an edition of the event.
+    pub fn puzzle_url(self, year: u16, day: u8) -> String {
+        match self {
+            Event::AdventOfCode => format!("https://adventofcode.com/{year}/day/{day}"),
+            Event::EverybodyCodes => {
+                format!("https://everybody.codes/event/{year}/quests/{day}")
+            }
+        }
+    }
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Event::AdventOfCode => "Advent of Code",
+            Event::EverybodyCodes => "Everybody Codes",
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Name of a part in the solution of a day, e.g. `part_two` for part 2.
+pub const PART_NAMES: [&str; 3] = ["part_one", "part_two", "part_three"];
+
+/// The part a function solves, derived from its name, e.g. `2` for `part_two_manual`.
+pub fn part_of(func: &str) -> Option<u8> {
+    PART_NAMES
+        .iter()
+        .zip(1..)
+        .find(|(name, _)| func.starts_with(**name))
+        .map(|(_, part)| part)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{part_of, Event};
+
+    #[test]
+    fn counts_days_of_events() {
+        assert_eq!(Event::AdventOfCode.day_count(2015), 25);
+        assert_eq!(Event::AdventOfCode.day_count(2024), 25);
+        assert_eq!(Event::AdventOfCode.day_count(2025), 12);
+        assert_eq!(Event::EverybodyCodes.day_count(2024), 20);
+    }
+
+    #[test]
+    fn derives_parts_from_names() {
+        assert_eq!(part_of("part_one"), Some(1));
+        assert_eq!(part_of("part_two_manual"), Some(2));
+        assert_eq!(part_of("part_three"), Some(3));
+        assert_eq!(part_of("parse"), None);
+    }
+}
@@ -0,0 +1,437 @@
+/// Examples of the puzzle descriptions and the answers they are expected to produce, used by the
+/// tests generated by [`examples!`](crate::examples), `solve --example` and `check-examples`.
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display},
+    fs, io,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::answers::parse_parts;
+use crate::template::{
+    current_year, part_of, paths, try_read_example, Day, InputError, Year, PART_NAMES,
+};
+
+fn get_path(year: Year) -> PathBuf {
+    paths::year_dir(year).join("examples.json")
+}
+
+/// An example file of a day, e.g. `01-2` for `data/2023/examples/01-2.txt`, and the answers it
+/// is expected to produce.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub day: Day,
+    pub file: String,
+    /// Expected answers of the parts, in order. Unknown answers are `None`.
+    pub parts: Vec<Option<String>>,
+}
+
+impl Example {
+    /// The expected answer for a part, if known.
+    pub fn expected(&self, part: u8) -> Option<&str> {
+        let index = usize::from(part).checked_sub(1)?;
+        self.parts.get(index)?.as_deref()
+    }
+}
+
+/// Represents the examples of a set of days, listed in `data/<year>/examples.json`.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExamplesManifest {
+    pub data: Vec<Example>,
+}
+
+impl ExamplesManifest {
+    /// Dehydrate the manifest to the JSON file of the current year.
+    pub fn store_file(&self) -> Result<(), io::Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(get_path(current_year()))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate the manifest from the JSON file of the current year.
+    /// If not present, returns an empty manifest.
+    pub fn read_from_file() -> Self {
+        Self::read_from_file_for(current_year())
+    }
+
+    /// Rehydrate the manifest from the JSON file of a year.
+    /// If not present, returns an empty manifest.
+    pub fn read_from_file_for(year: Year) -> Self {
+        let path = get_path(year);
+        let Ok(s) = fs::read_to_string(&path) else {
+            return ExamplesManifest::default();
+        };
+
+        match ExamplesManifest::try_from(s) {
+            Ok(manifest) => manifest,
+            Err(e) => {
+                eprintln!("{}: {e}", path.display());
+                ExamplesManifest::default()
+            }
+        }
+    }
+
+    /// Add an example, unless the manifest already lists its file.
+    /// Returns whether the example was added.
+    pub fn add(&mut self, example: Example) -> bool {
+        if self
+            .data
+            .iter()
+            .any(|e| e.day == example.day && e.file == example.file)
+        {
+            return false;
+        }
+
+        self.data.push(example);
+        self.data
+            .sort_by(|a, b| a.day.cmp(&b.day).then_with(|| a.file.cmp(&b.file)));
+        true
+    }
+
+    /// The examples of a day that have an expected answer for a part.
+    pub fn for_part(&self, day: Day, part: u8) -> impl Iterator<Item = &Example> {
+        self.data
+            .iter()
+            .filter(move |e| e.day == day && e.expected(part).is_some())
+    }
+}
+
+/// Result of running a part against an example.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Passed,
+    Mismatch {
+        actual: Option<String>,
+    },
+    Panicked,
+    /// The example file could not be read.
+    Unreadable(String),
+}
+
+/// The outcome of checking a part against an example.
+#[derive(Clone, Debug)]
+pub struct ExampleCheck<'a> {
+    pub example: &'a Example,
+    pub part: u8,
+    pub outcome: Outcome,
+}
+
+impl ExampleCheck<'_> {
+    pub fn is_passed(&self) -> bool {
+        self.outcome == Outcome::Passed
+    }
+}
+
+impl Display for ExampleCheck<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let expected = self.example.expected(self.part).unwrap_or_default();
+        write!(f, "Part {} ({}): ", self.part, self.example.file)?;
+
+        match &self.outcome {
+            Outcome::Passed => write!(f, "✓ {expected}"),
+            Outcome::Mismatch {
+                actual: Some(actual),
+            } => {
+                write!(f, "✖ expected {expected}, got {actual}")
+            }
+            Outcome::Mismatch { actual: None } => write!(f, "✖ expected {expected}, got no answer"),
+            Outcome::Panicked => write!(f, "✖ panicked"),
+            Outcome::Unreadable(e) => write!(f, "✖ {e}"),
+        }
+    }
+}
+
+/// Run a part of a day against every example in the manifest of its year that has an expected
+/// answer for it. `solve` returns the answer of the part formatted with `Display`.
+pub fn check_part<'a>(
+    manifest: &'a ExamplesManifest,
+    year: Year,
+    day: Day,
+    part: u8,
+    solve: impl Fn(&str) -> Option<String>,
+) -> Vec<ExampleCheck<'a>> {
+    manifest
+        .for_part(day, part)
+        .map(|example| {
+            let outcome = match try_read_example(year, day, &example.file) {
+                Ok(input) => match panic::catch_unwind(AssertUnwindSafe(|| solve(&input))) {
+                    Ok(actual) if actual.as_deref() == example.expected(part) => Outcome::Passed,
+                    Ok(actual) => Outcome::Mismatch { actual },
+                    Err(_) => Outcome::Panicked,
+                },
+                Err(e) => Outcome::Unreadable(e.to_string()),
+            };
+
+            ExampleCheck {
+                example,
+                part,
+                outcome,
+            }
+        })
+        .collect()
+}
+
+/// Check a part against all examples of its day in `data/<year>/examples.json`, panics with every
+/// failed example. The part is derived from the name of the function, e.g. `part_two_manual`
+/// solves part 2.
+#[track_caller]
+pub fn assert_manifest(year: Year, day: Day, func: &str, solve: impl Fn(&str) -> Option<String>) {
+    let part = part_of(func).unwrap_or_else(|| {
+        panic!(
+            "can not tell which part `{func}` solves, its name should start with one of {}.",
+            PART_NAMES.join(", ")
+        )
+    });
+
+    let manifest = ExamplesManifest::read_from_file_for(year);
+    let checks = check_part(&manifest, year, day, part, solve);
+
+    if checks.is_empty() {
+        panic!(
+            "\"{}\" lists no example of day {day} with an answer for part {part}.",
+            get_path(year).display()
+        );
+    }
+
+    let failures: Vec<String> = checks
+        .iter()
+        .filter(|check| !check.is_passed())
+        .map(ToString::to_string)
+        .collect();
+
+    if !failures.is_empty() {
+        panic!(
+            "`{func}` failed {} example(s):\n{}",
+            failures.len(),
+            failures.join("\n")
+        );
+    }
+}
+
+/// Read a named example file of a day of a year, panics with a description of the problem if it
+/// is not usable.
+#[must_use]
+pub fn read_example(year: Year, day: Day, name: &str) -> String {
+    try_read_example(year, day, name).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Read an example that is expected to be unsolved, see [`read_example`]. An empty example file
+/// is read as an empty string, so that the tests `scaffold` generates pass until the example is
+/// filled in.
+pub fn read_pending_example(year: Year, day: Day, name: &str) -> String {
+    match try_read_example(year, day, name) {
+        Ok(input) => input,
+        Err(InputError::Empty { .. }) => String::new(),
+        Err(e) => panic!("{e}"),
+    }
+}
+
+/// Compare the answer of a part to the expected answer of an example. On failure, the expected
+/// and actual answer are printed below each other to make them easy to diff.
+#[track_caller]
+pub fn assert_example<T: Debug + PartialEq>(func: &str, example: &str, actual: T, expected: T) {
+    if actual != expected {
+        panic!("{}", format_mismatch(func, example, &actual, &expected));
+    }
+}
+
+fn format_mismatch<T: Debug>(func: &str, example: &str, actual: &T, expected: &T) -> String {
+    format!("`{func}` returned a wrong answer for example {example}:\n  expected: {expected:?}\n    actual: {actual:?}")
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<ExamplesManifest> for JsonValue {
+    fn from(value: ExamplesManifest) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for ExamplesManifest {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(ExamplesManifest {
+            data: json_data
+                .iter()
+                .map(Example::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<&Example> for JsonValue {
+    fn from(value: &Example) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("file".into(), JsonValue::String(value.file.clone()));
+
+        // parts without an example answer are left out, which reads better than `null`.
+        for (part, answer) in (1..).zip(&value.parts) {
+            if let Some(answer) = answer {
+                map.insert(format!("part_{part}"), JsonValue::String(answer.clone()));
+            }
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Example {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected example to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected example.day to be a Day struct.")?;
+
+        let file = json
+            .get("file")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected example.file to be a string.")?;
+
+        let parts = parse_parts(json, "example")?;
+
+        Ok(Example {
+            day,
+            file: file.clone(),
+            parts,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        assert_example, format_mismatch, Example, ExampleCheck, ExamplesManifest, Outcome,
+    };
+    use crate::day;
+    use tinyjson::JsonValue;
+
+    #[test]
+    fn reads_manifest() {
+        let json = r#"{ "data": [
+            { "day": "01", "file": "01-1", "part_1": 142 },
+            { "day": "01", "file": "01-2", "part_1": null, "part_2": "281" },
+            { "day": "02", "file": "02", "part_1": 8, "part_2": 2286 }
+        ] }"#
+            .to_string();
+        let manifest = ExamplesManifest::try_from(json).unwrap();
+
+        let files = |day, part| -> Vec<&str> {
+            manifest
+                .for_part(day, part)
+                .map(|e| e.file.as_str())
+                .collect()
+        };
+
+        assert_eq!(files(day!(1), 1), ["01-1"]);
+        assert_eq!(files(day!(1), 2), ["01-2"]);
+        assert_eq!(files(day!(2), 2), ["02"]);
+        assert!(files(day!(3), 1).is_empty());
+    }
+
+    #[test]
+    fn adds_examples_once() {
+        let example = |file: &str, part_1: &str| Example {
+            day: day!(1),
+            file: file.into(),
+            parts: vec![Some(part_1.into())],
+        };
+
+        let mut manifest = ExamplesManifest::default();
+        assert!(manifest.add(example("01-2", "7")));
+        assert!(manifest.add(example("01-1", "142")));
+        assert!(!manifest.add(example("01-1", "143")));
+        assert_eq!(
+            manifest.data,
+            [example("01-1", "142"), example("01-2", "7")]
+        );
+
+        let json = JsonValue::from(manifest.clone()).stringify().unwrap();
+        assert_eq!(ExamplesManifest::try_from(json).unwrap(), manifest);
+    }
+
+    #[test]
+    fn rejects_malformed_manifest() {
+        let json = r#"{ "data": [{ "day": "01", "part_1": 142 }] }"#.to_string();
+        assert!(ExamplesManifest::try_from(json).is_err());
+    }
+
+    #[test]
+    fn describes_checks() {
+        let example = Example {
+            day: day!(1),
+            file: "01-1".into(),
+            parts: vec![Some("142".into())],
+        };
+        let check = |outcome| ExampleCheck {
+            example: &example,
+            part: 1,
+            outcome,
+        };
+
+        assert_eq!(check(Outcome::Passed).to_string(), "Part 1 (01-1): ✓ 142");
+        assert_eq!(
+            check(Outcome::Mismatch {
+                actual: Some("141".into())
+            })
+            .to_string(),
+            "Part 1 (01-1): ✖ expected 142, got 141"
+        );
+        assert_eq!(
+            check(Outcome::Mismatch { actual: None }).to_string(),
+            "Part 1 (01-1): ✖ expected 142, got no answer"
+        );
+    }
+
+    #[test]
+    fn formats_mismatches() {
+        assert_eq!(
+            format_mismatch("part_one", "\"01-1\"", &Some(141), &Some(142)),
+            "`part_one` returned a wrong answer for example \"01-1\":\n  expected: Some(142)\n    actual: Some(141)"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "expected: Some(142)")]
+    fn panics_on_mismatch() {
+        assert_example("part_one", "\"01-1\"", Some(141), Some(142));
+    }
+
+    #[test]
+    fn accepts_matching_answers() {
+        assert_example("part_two", "\"two1nine\"", Some(29), Some(29));
+        assert_example::<Option<u32>>("part_two", "\"01-2\"", None, None);
+    }
+}
@@ -0,0 +1,308 @@
+/// Reading of puzzle inputs and examples, with errors that explain how to fix a broken file.
+use std::error::Error;
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::template::{paths, Day, Year};
+
+/// An error which can be returned when reading an input or example file.
+#[derive(Debug)]
+pub enum InputError {
+    /// The file does not exist.
+    Missing {
+        path: PathBuf,
+        folder: String,
+        day: Day,
+    },
+    /// The file exists but is empty, e.g. because it was just created by `scaffold`.
+    Empty {
+        path: PathBuf,
+        folder: String,
+        day: Day,
+    },
+    /// The file uses Windows line endings, which most solutions do not expect.
+    CrLf { path: PathBuf },
+    /// The file contains an error page instead of a puzzle input, e.g. because the session
+    /// cookie expired or the puzzle was not unlocked yet when downloading.
+    ErrorPage { path: PathBuf, day: Day },
+    /// Any other IO error.
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Missing { path, folder, day } => {
+                write!(f, "\"{}\" does not exist. ", path.display())?;
+                if folder == "inputs" {
+                    write!(
+                        f,
+                        "Run `cargo download {}` to download it.",
+                        day.into_inner()
+                    )
+                } else {
+                    write!(
+                        f,
+                        "Run `cargo scaffold {}` or create it manually.",
+                        day.into_inner()
+                    )
+                }
+            }
+            InputError::Empty { path, folder, day } => {
+                write!(f, "\"{}\" is empty. ", path.display())?;
+                if folder == "inputs" {
+                    write!(
+                        f,
+                        "Run `cargo download {}` to download your input.",
+                        day.into_inner()
+                    )
+                } else {
+                    write!(
+                        f,
+                        "Paste the example of the puzzle description into it, `cargo read {}` prints the description.",
+                        day.into_inner()
+                    )
+                }
+            }
+            InputError::CrLf { path } => write!(
+                f,
+                "\"{}\" uses CRLF line endings. Pass `--normalize` to convert them when solving, \
+                or convert the file to LF line endings, e.g. with `dos2unix`.",
+                path.display()
+            ),
+            InputError::ErrorPage { path, day } => write!(
+                f,
+                "\"{}\" contains an error page instead of a puzzle input. Check that your session \
+                cookie is valid and that the puzzle is unlocked, then run `cargo download {}`.",
+                path.display(),
+                day.into_inner()
+            ),
+            InputError::Io { path, source } => {
+                write!(f, "could not read \"{}\": {source}", path.display())
+            }
+        }
+    }
+}
+
+/// Read a text file of a day from a subfolder of the current year, e.g. `data/2023/inputs/01.txt`.
+pub fn try_read_file(folder: &str, day: Day) -> Result<String, InputError> {
+    let path = paths::data_dir(folder).join(format!("{day}.txt"));
+    read_checked(&path, folder, day)
+}
+
+/// Read a text file of a day from a subfolder of the current year, appending a part suffix.
+/// E.g. like `01-2.txt`.
+pub fn try_read_file_part(folder: &str, day: Day, part: u8) -> Result<String, InputError> {
+    let path = paths::data_dir(folder).join(format!("{day}-{part}.txt"));
+    read_checked(&path, folder, day)
+}
+
+/// Read a named example file of a day of a year, e.g. `01-2` for `data/2023/examples/01-2.txt`.
+pub fn try_read_example(year: Year, day: Day, name: &str) -> Result<String, InputError> {
+    let path = paths::year_dir(year)
+        .join("examples")
+        .join(format!("{name}.txt"));
+    read_checked(&path, "examples", day)
+}
+
+/// Read the puzzle input of a day. If `normalize` is set, line endings and trailing whitespace
+/// are normalized before the input is checked, see [`normalize`].
+///
+/// Also returns the raw contents of the file, e.g. to hash them.
+pub fn try_read_input(day: Day, normalize: bool) -> Result<(String, String), InputError> {
+    let path = paths::data_dir("inputs").join(format!("{day}.txt"));
+    let raw = read_raw(&path, "inputs", day)?;
+    Ok((prepare_input(raw.clone(), &path, day, normalize)?, raw))
+}
+
+/// Check the raw contents of the puzzle input of a day, normalizing them first if requested.
+/// `path` is the location the contents were read from, used for error messages.
+pub fn prepare_input(
+    raw: String,
+    path: &Path,
+    day: Day,
+    normalize: bool,
+) -> Result<String, InputError> {
+    let contents = if normalize {
+        self::normalize(&raw)
+    } else {
+        raw
+    };
+
+    check_contents(contents, path, "inputs", day)
+}
+
+/// Convert line endings to `\n` by removing all `\r`, remove trailing whitespace and end the input with a single newline.
+/// Whitespace within and at the start of lines is kept, as it can be significant for grids.
+pub fn normalize(contents: &str) -> String {
+    let mut normalized = contents.replace('\r', "");
+    normalized.truncate(normalized.trim_end().len());
+    normalized.push('\n');
+    normalized
+}
+
+/// Heuristics for inputs that look truncated, e.g. because they were copied by hand.
+/// Returns a description of every problem found.
+pub fn sanity_warnings(contents: &str) -> Vec<String> {
+    let mut warnings = vec![];
+
+    // inputs served by the website always end with a newline.
+    if !contents.is_empty() && !contents.ends_with('\n') {
+        warnings.push("input does not end with a newline, it might be truncated.".into());
+    }
+
+    // in grid-like inputs, all lines have the same length.
+    let lines: Vec<&str> = contents.trim_end().lines().collect();
+    if let [rest @ .., last] = lines.as_slice() {
+        let width = rest.first().map_or(0, |l| l.len());
+        let is_grid = rest.len() >= 2 && width > 0 && rest.iter().all(|l| l.len() == width);
+
+        if is_grid && last.len() < width {
+            warnings.push(format!(
+                "last line is shorter than the others ({} vs. {width} characters), the input might be truncated.",
+                last.len()
+            ));
+        }
+    }
+
+    warnings
+}
+
+fn read_raw(path: &Path, folder: &str, day: Day) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|source| match source.kind() {
+        io::ErrorKind::NotFound => InputError::Missing {
+            path: path.to_path_buf(),
+            folder: folder.into(),
+            day,
+        },
+        _ => InputError::Io {
+            path: path.to_path_buf(),
+            source,
+        },
+    })
+}
+
+fn read_checked(path: &Path, folder: &str, day: Day) -> Result<String, InputError> {
+    let contents = read_raw(path, folder, day)?;
+    check_contents(contents, path, folder, day)
+}
+
+/// Reject contents that can not be a valid puzzle input.
+fn check_contents(
+    contents: String,
+    path: &Path,
+    folder: &str,
+    day: Day,
+) -> Result<String, InputError> {
+    if contents.trim().is_empty() {
+        return Err(InputError::Empty {
+            path: path.to_path_buf(),
+            folder: folder.into(),
+            day,
+        });
+    }
+
+    if is_error_page(&contents) {
+        return Err(InputError::ErrorPage {
+            path: path.to_path_buf(),
+            day,
+        });
+    }
+
+    if contents.contains("\r\n") {
+        return Err(InputError::CrLf {
+            path: path.to_path_buf(),
+        });
+    }
+
+    Ok(contents)
+}
+
+/// Whether the contents look like an HTML page or the plain-text error the server sends to
+/// requests without a valid session.
+fn is_error_page(contents: &str) -> bool {
+    let prefix: String = contents.trim_start().chars().take(64).collect();
+    let prefix = prefix.to_ascii_lowercase();
+
+    prefix.starts_with("<!doctype html")
+        || prefix.starts_with("<html")
+        || prefix.starts_with("puzzle inputs differ by user")
+        || prefix.starts_with("please don't repeatedly request this endpoint")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{check_contents, normalize, sanity_warnings, InputError};
+    use crate::day;
+    use std::path::Path;
+
+    fn check(contents: &str, folder: &str) -> Result<String, InputError> {
+        check_contents(contents.into(), Path::new("01.txt"), folder, day!(1))
+    }
+
+    #[test]
+    fn accepts_valid_inputs() {
+        assert_eq!(check("1abc2\n", "inputs").unwrap(), "1abc2\n");
+    }
+
+    #[test]
+    fn rejects_broken_inputs() {
+        assert!(matches!(check("", "inputs"), Err(InputError::Empty { .. })));
+        assert!(matches!(
+            check(" \n", "examples"),
+            Err(InputError::Empty { .. })
+        ));
+        assert!(matches!(
+            check("1\r\n2\r\n", "inputs"),
+            Err(InputError::CrLf { .. })
+        ));
+        assert!(matches!(
+            check("<!DOCTYPE html>\n<html lang=\"en-us\">", "inputs"),
+            Err(InputError::ErrorPage { .. })
+        ));
+        assert!(matches!(
+            check(
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+                "inputs"
+            ),
+            Err(InputError::ErrorPage { .. })
+        ));
+    }
+
+    #[test]
+    fn suggests_fixes() {
+        let err = check("", "inputs").unwrap_err().to_string();
+        assert!(err.contains("cargo download 1"));
+
+        let err = check("", "examples").unwrap_err().to_string();
+        assert!(err.contains("cargo read 1"));
+    }
+
+    #[test]
+    fn normalizes_inputs() {
+        assert_eq!(normalize("a\r\nb\r\n\r\n"), "a\nb\n");
+        assert_eq!(normalize("  a \n b"), "  a \n b\n");
+        assert_eq!(normalize("a\n\n\n  "), "a\n");
+    }
+
+    #[test]
+    fn warns_about_truncated_inputs() {
+        assert!(sanity_warnings("#..\n.#.\n..#\n").is_empty());
+        assert!(sanity_warnings("1\n22\n3\n").is_empty());
+        assert_eq!(sanity_warnings("#..\n.#.\n..#").len(), 1);
+        assert_eq!(sanity_warnings("#..\n.#.\n.").len(), 2);
+    }
+}
@@ -0,0 +1,205 @@
+use std::{collections::HashMap, fs, io::Error, path::PathBuf, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{paths, Day};
+
+fn get_path() -> PathBuf {
+    paths::data_file("input_hashes.json")
+}
+
+/// Represents the hash of the puzzle input of a single day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputHash {
+    pub day: Day,
+    pub hash: String,
+}
+
+/// Represents the hashes of the puzzle inputs of a set of days, used to notice inputs that changed.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InputHashes {
+    pub data: Vec<InputHash>,
+}
+
+impl InputHashes {
+    /// Dehydrate hashes to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(get_path())?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate hashes from a JSON file. If not present, returns empty hashes.
+    pub fn read_from_file() -> Self {
+        let path = get_path();
+        let Ok(s) = fs::read_to_string(&path) else {
+            return InputHashes::default();
+        };
+
+        match InputHashes::try_from(s) {
+            Ok(hashes) => hashes,
+            Err(e) => {
+                eprintln!("{}: {e}", path.display());
+                InputHashes::default()
+            }
+        }
+    }
+
+    pub fn get(&self, day: Day) -> Option<&str> {
+        self.data
+            .iter()
+            .find(|h| h.day == day)
+            .map(|h| h.hash.as_str())
+    }
+
+    /// Record the hash of a day, overwriting previous values.
+    pub fn set(&mut self, day: Day, hash: &str) {
+        match self.data.iter_mut().find(|h| h.day == day) {
+            Some(entry) => entry.hash = hash.into(),
+            None => {
+                self.data.push(InputHash {
+                    day,
+                    hash: hash.into(),
+                });
+                self.data.sort_unstable_by_key(|h| h.day);
+            }
+        }
+    }
+}
+
+/// Hash the contents of an input with 64-bit FNV-1a. Stable across platforms and rust versions,
+/// unlike the hasher of the standard library.
+pub fn hash_input(contents: &str) -> String {
+    let hash = contents
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+
+    format!("{hash:016x}")
+}
+
+/// Record the hash of the input of a day.
+/// Returns the previously recorded hash if the input changed since it was last recorded.
+pub fn record(day: Day, contents: &str) -> Option<String> {
+    let hash = hash_input(contents);
+    let mut hashes = InputHashes::read_from_file();
+
+    let previous = hashes.get(day).map(String::from);
+    if previous.as_deref() == Some(hash.as_str()) {
+        return None;
+    }
+
+    hashes.set(day, &hash);
+    if let Err(e) = hashes.store_file() {
+        eprintln!("Warning: could not store input hash: {e}");
+    }
+
+    previous
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<InputHashes> for JsonValue {
+    fn from(value: InputHashes) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for InputHashes {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(InputHashes {
+            data: json_data
+                .iter()
+                .map(InputHash::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&InputHash> for JsonValue {
+    fn from(value: &InputHash) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("hash".into(), JsonValue::String(value.hash.clone()));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for InputHash {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected input hash to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected input_hash.day to be a Day struct.")?;
+
+        let hash = json
+            .get("hash")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected input_hash.hash to be a string.")?;
+
+        Ok(InputHash {
+            day,
+            hash: hash.clone(),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{hash_input, InputHashes};
+    use crate::day;
+    use tinyjson::JsonValue;
+
+    #[test]
+    fn hashes_inputs() {
+        assert_eq!(hash_input(""), "cbf29ce484222325");
+        assert_eq!(hash_input("a"), "af63dc4c8601ec8c");
+        assert_ne!(hash_input("1abc2\n"), hash_input("1abc2"));
+    }
+
+    #[test]
+    fn roundtrips_hashes() {
+        let mut hashes = InputHashes::default();
+        hashes.set(day!(3), "b");
+        hashes.set(day!(1), "a");
+        hashes.set(day!(3), "c");
+        assert_eq!(hashes.data.len(), 2);
+        assert_eq!(hashes.get(day!(3)), Some("c"));
+
+        let json = JsonValue::from(hashes.clone()).stringify().unwrap();
+        assert_eq!(InputHashes::try_from(json).unwrap(), hashes);
+    }
+}
@@ -1,46 +1,101 @@
-use std::{env, fs};
-
+pub mod answers;
 pub mod aoc_cli;
+pub mod bench_reports;
 pub mod commands;
+pub mod examples;
+pub mod output;
+pub mod paths;
+pub mod profiles;
+pub mod puzzle;
+pub mod registry;
+pub mod report;
 pub mod runner;
+pub mod scheduling;
+pub mod selection;
 
 pub use day::*;
+pub use event::*;
+pub use input::*;
+pub use year::*;
 
+mod comparison;
 mod day;
+mod event;
+mod input;
+mod input_hashes;
 mod readme_benchmarks;
 mod run_multi;
 mod timings;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
 /// Helper function that reads a text file to a string.
+/// Panics with a description of the problem if the file is missing or not a valid input,
+/// see [`try_read_file`] for a fallible version.
 #[must_use]
 pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    try_read_file(folder, day).unwrap_or_else(|e| panic!("{e}"))
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
+/// Panics with a description of the problem if the file is missing or not a valid input,
+/// see [`try_read_file_part`] for a fallible version.
 #[must_use]
 pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    try_read_file_part(folder, day, part).unwrap_or_else(|e| panic!("{e}"))
 }
 
 /// Creates the constant `DAY` and sets up the input and runner for each part.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+///
+/// Solutions can live in a module of the library under `src/days/y<year>`, so that other days,
+/// benches and crates can reuse them. The module declares its day with `solution!(lib 1)` and
+/// re-exports the `YEAR` of its parent module, the binary imports the module and declares the
+/// runner as usual:
+///
+/// ```ignore
+/// use advent_of_code::days::y2023::day01::*;
+///
+/// advent_of_code::solution!(1);
+/// ```
+///
+/// The binary selects the `YEAR` it imports, so that it reads the data of its own year.
+///
+/// The module also registers its part functions as `SOLUTION`, which the module of its year
+/// collects into a list of all days for `cargo bench`. Pass `1` or `2` if only one part exists, or
+/// `parse: <fn>` to bench a parser of the input on its own, e.g. `solution!(lib 1, parse: parse)`.
+///
+/// Events with more than two parts per day list their parts explicitly, both in the module and
+/// the binary, e.g. `solution!(lib 1, parts: [part_one, part_two, part_three])`. Parts are
+/// numbered by their name, see [`PART_NAMES`].
+///
+/// Alternative implementations of a part can be registered as named variants, e.g.
+/// `solution!(1, part_two: [part_two_manual])`. Passing `--variants` to the binary checks that all
+/// variants agree on the answer and benches them against each other.
 #[macro_export]
 macro_rules! solution {
+    (lib $day:expr) => {
+        $crate::solution!(@lib $day, [] part_one part_two);
+    };
+    (lib $day:expr, 1) => {
+        $crate::solution!(@lib $day, [] part_one);
+    };
+    (lib $day:expr, 2) => {
+        $crate::solution!(@lib $day, [] part_two);
+    };
+    (lib $day:expr, parse: $parse:ident) => {
+        $crate::solution!(@lib $day, [$parse] part_one part_two);
+    };
+    (lib $day:expr, parts: [$( $part:ident ),+ $(,)?]) => {
+        $crate::solution!(@lib $day, [] $( $part )+);
+    };
+    (lib $day:expr, parse: $parse:ident, parts: [$( $part:ident ),+ $(,)?]) => {
+        $crate::solution!(@lib $day, [$parse] $( $part )+);
+    };
     ($day:expr) => {
         $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
     };
@@ -50,10 +105,66 @@ macro_rules! solution {
     ($day:expr, 2) => {
         $crate::solution!(@impl $day, [part_two, 2]);
     };
+    ($day:expr, parts: [$( $part:ident ),+ $(,)?] $(, $func:ident: [ $( $variant:ident ),+ $(,)? ] )* $(,)?) => {
+        $crate::solution!(
+            @impl $day, $( [$part, $crate::solution!(@part $part)] )+ $( { $func, [ $( $variant ),+ ] } )*
+        );
+    };
+    ($day:expr, $( $func:ident: [ $( $variant:ident ),+ $(,)? ] ),+ $(,)?) => {
+        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2] $( { $func, [ $( $variant ),+ ] } )+);
+    };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@input_path $day:expr) => {
+        env!(
+            concat!("AOC_INPUT_", env!("CARGO_BIN_NAME")),
+            concat!(
+                "input of ", env!("CARGO_BIN_NAME"), " does not exist, ",
+                "run `cargo download ", stringify!($day), " --year ",
+                env!(concat!("AOC_BIN_YEAR_", env!("CARGO_BIN_NAME"))),
+                "` before building with the `embed-inputs` feature."
+            )
+        )
+    };
+
+    (@lib $day:expr, [$( $parse:ident )?] $( $part:ident )+) => {
+        pub use super::YEAR;
+
+        /// The current day.
+        pub const DAY: $crate::template::Day = $crate::day!($day, in YEAR);
+
+        /// Entry points of the solution of the current day, see `template::registry`.
+        pub const SOLUTION: $crate::template::registry::Solution = $crate::template::registry::Solution {
+            year: YEAR,
+            day: DAY,
+            parse: $crate::solution!(@entry $( $parse )?),
+            parts: &[$( $crate::solution!(@registered $part) ),+],
+        };
+    };
+
+    (@entry) => { None };
+    (@entry $func:ident) => {
+        Some(|input: &str| {
+            ::std::hint::black_box($func(input));
+        })
+    };
+
+    (@registered $func:ident) => {
+        $crate::template::registry::Part {
+            number: $crate::solution!(@part $func),
+            run: |input: &str| {
+                ::std::hint::black_box($func(input));
+            },
+            answer: |input: &str| $func(input).map(|answer| answer.to_string()),
+        }
+    };
+
+    (@part part_one) => { 1 };
+    (@part part_two) => { 2 };
+    (@part part_three) => { 3 };
+
+    (@impl $day:expr, $( [$func:expr, $part:expr] )* $( { $variant_func:ident, [ $( $variant:ident ),+ ] } )*) => {
         /// The current day.
-        const DAY: $crate::template::Day = $crate::day!($day);
+        const DAY: $crate::template::Day = $crate::day!($day, in YEAR);
 
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
@@ -61,8 +172,159 @@ macro_rules! solution {
 
         fn main() {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
+
+            $crate::template::set_year(YEAR);
+
+            if std::env::args().any(|x| x == "--example") {
+                let passed: &[bool] = &[$( run_examples($func, DAY, $part) ),*];
+
+                if passed.contains(&false) {
+                    std::process::exit(1);
+                }
+
+                return;
+            }
+
+            #[cfg(not(feature = "embed-inputs"))]
+            let input = read_input(DAY);
+
+            #[cfg(feature = "embed-inputs")]
+            let input = read_embedded_input(
+                DAY,
+                $crate::solution!(@input_path $day),
+                include_str!($crate::solution!(@input_path $day)),
+            );
+
+            if std::env::args().any(|x| x == "--variants") {
+                let agreed: &[bool] = &[$(
+                    run_variants(
+                        &[
+                            (stringify!($variant_func), $variant_func as fn(_) -> _),
+                            $( (stringify!($variant), $variant as fn(_) -> _), )+
+                        ],
+                        input.as_str(),
+                        $crate::solution!(@part $variant_func),
+                    )
+                ),*];
+
+                if agreed.is_empty() {
+                    eprintln!(
+                        "No variants registered for day {DAY}. Register them like `solution!({}, part_two: [part_two_alt])`.",
+                        DAY.into_inner()
+                    );
+                }
+
+                if agreed.contains(&false) {
+                    std::process::exit(1);
+                }
+
+                return;
+            }
+
             $( run_part($func, &input, DAY, $part); )*
         }
     };
 }
+
+/// Generates a test for every example of a day, checking the answer of a part against the
+/// expected answer:
+///
+/// ```ignore
+/// advent_of_code::examples! {
+///     part_one: "01-1" => 142,
+///     part_two: "01-2" => 281,
+///     part_two: inline "two1nine" => 29,
+///     part_two_manual: "01-2" => None,
+/// }
+/// ```
+///
+/// A string names an example file in `data/<year>/examples` without its extension, `inline`
+/// passes the string itself as input. `None` expects the part to be unsolved and accepts an empty
+/// example file, e.g. one just created by `scaffold`. Any other answer is wrapped in `Some`.
+/// Tests are named after their position and part, e.g. `examples::example_3::part_two`.
+///
+/// Alternatively, the expected answers can be read from `data/<year>/examples.json`, which
+/// generates a test per function that checks it against every example of the day listed there:
+///
+/// ```ignore
+/// advent_of_code::examples!(manifest: [part_one, part_two, part_two_manual]);
+/// ```
+#[macro_export]
+macro_rules! examples {
+    (manifest: [$( $func:ident ),+ $(,)?]) => {
+        #[cfg(test)]
+        mod manifest_examples {
+            use super::*;
+
+            $(
+                #[test]
+                fn $func() {
+                    $crate::template::examples::assert_manifest(
+                        YEAR,
+                        DAY,
+                        stringify!($func),
+                        |input| super::$func(input).map(|answer| answer.to_string()),
+                    );
+                }
+            )+
+        }
+    };
+
+    (@munch [$( $names:ident )*]) => {};
+    (@munch [] $( $rest:tt )+) => {
+        compile_error!("`examples!` supports up to 20 examples, split them into several modules.");
+    };
+    (@munch [$( $names:ident )*] $func:ident: inline $source:literal => None $(, $( $rest:tt )* )?) => {
+        $crate::examples!(@test [$( $names )*] $func, (String::from($source)), $source, None, $( $( $rest )* )?);
+    };
+    (@munch [$( $names:ident )*] $func:ident: inline $source:literal => $expected:expr $(, $( $rest:tt )* )?) => {
+        $crate::examples!(@test [$( $names )*] $func, (String::from($source)), $source, Some($expected), $( $( $rest )* )?);
+    };
+    (@munch [$( $names:ident )*] $func:ident: $source:literal => None $(, $( $rest:tt )* )?) => {
+        $crate::examples!(
+            @test [$( $names )*] $func,
+            ($crate::template::examples::read_pending_example(super::YEAR, super::DAY, $source)),
+            $source, None, $( $( $rest )* )?
+        );
+    };
+    (@munch [$( $names:ident )*] $func:ident: $source:literal => $expected:expr $(, $( $rest:tt )* )?) => {
+        $crate::examples!(
+            @test [$( $names )*] $func,
+            ($crate::template::examples::read_example(super::YEAR, super::DAY, $source)),
+            $source, Some($expected), $( $( $rest )* )?
+        );
+    };
+
+    (@test [$name:ident $( $names:ident )*] $func:ident, $input:expr, $source:literal, $expected:expr, $( $rest:tt )*) => {
+        mod $name {
+            #[test]
+            fn $func() {
+                let input = $input;
+                $crate::template::examples::assert_example(
+                    stringify!($func),
+                    stringify!($source),
+                    super::$func(&input),
+                    $expected,
+                );
+            }
+        }
+
+        $crate::examples!(@munch [$( $names )*] $( $rest )*);
+    };
+
+    ($( $examples:tt )+) => {
+        #[cfg(test)]
+        mod examples {
+            use super::*;
+
+            $crate::examples!(
+                @munch [
+                    example_1 example_2 example_3 example_4 example_5 example_6 example_7
+                    example_8 example_9 example_10 example_11 example_12 example_13 example_14
+                    example_15 example_16 example_17 example_18 example_19 example_20
+                ]
+                $( $examples )+
+            );
+        }
+    };
+}
@@ -0,0 +1,120 @@
+/// Terminal-aware output styling and verbosity levels shared by all commands and solution bins.
+use std::env;
+use std::fmt::Display;
+use std::io::{self, IsTerminal};
+use std::process::Command;
+use std::sync::OnceLock;
+
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// Environment variable that passes the verbosity level on to child processes.
+const VERBOSITY_ENV: &str = "AOC_VERBOSITY";
+
+/// Environment variable that tells a child process with piped stdout whether its output
+/// ends up in a terminal, so that it can style its output like the parent.
+const TERMINAL_ENV: &str = "AOC_TERMINAL";
+
+/// How much output commands and solution bins print.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    /// Answers only.
+    Quiet,
+    #[default]
+    Normal,
+    /// Additional timing breakdowns and build details.
+    Verbose,
+}
+
+impl Verbosity {
+    fn as_str(self) -> &'static str {
+        match self {
+            Verbosity::Quiet => "quiet",
+            Verbosity::Normal => "normal",
+            Verbosity::Verbose => "verbose",
+        }
+    }
+
+    /// The verbosity of the current process, as set by [`set_verbosity`] or inherited from the parent.
+    pub fn current() -> Self {
+        match env::var(VERBOSITY_ENV).as_deref() {
+            Ok("quiet") => Verbosity::Quiet,
+            Ok("verbose") => Verbosity::Verbose,
+            _ => Verbosity::Normal,
+        }
+    }
+}
+
+/// Set the verbosity for this process and all child processes spawned afterwards.
+/// Needs to be called before any other threads are spawned.
+pub fn set_verbosity(verbosity: Verbosity) {
+    env::set_var(VERBOSITY_ENV, verbosity.as_str());
+}
+
+pub fn is_quiet() -> bool {
+    Verbosity::current() == Verbosity::Quiet
+}
+
+pub fn is_verbose() -> bool {
+    Verbosity::current() == Verbosity::Verbose
+}
+
+/// Whether stdout ends up in a terminal. Redraws via `\r` are only used if this is the case.
+pub fn is_terminal() -> bool {
+    static IS_TERMINAL: OnceLock<bool> = OnceLock::new();
+
+    *IS_TERMINAL.get_or_init(|| match env::var(TERMINAL_ENV).as_deref() {
+        Ok("1") => true,
+        Ok("0") => false,
+        _ => io::stdout().is_terminal(),
+    })
+}
+
+/// Whether output should be styled. Honours the `NO_COLOR` convention, see <https://no-color.org>.
+pub fn is_color_enabled() -> bool {
+    let no_color = env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+    !no_color && is_terminal()
+}
+
+/// Configure a solution bin whose stdout is captured by this process.
+/// In quiet mode, the child prints its regular output so that it can be parsed and summarized.
+///
+/// If `forwarded` is set, the captured output is printed to our stdout and the child styles
+/// its output as if it was writing to our stdout directly.
+pub fn configure_child(cmd: &mut Command, forwarded: bool) {
+    let is_terminal = forwarded && is_terminal();
+    cmd.env(TERMINAL_ENV, if is_terminal { "1" } else { "0" });
+
+    if is_quiet() {
+        cmd.env(VERBOSITY_ENV, Verbosity::Normal.as_str());
+    }
+}
+
+/// A value that is wrapped in an ANSI style if styling is enabled.
+pub struct Styled<T> {
+    style: &'static str,
+    value: T,
+}
+
+impl<T: Display> Display for Styled<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if is_color_enabled() {
+            write!(f, "{}{}{ANSI_RESET}", self.style, self.value)
+        } else {
+            write!(f, "{}", self.value)
+        }
+    }
+}
+
+pub fn bold<T: Display>(value: T) -> Styled<T> {
+    Styled {
+        style: ANSI_BOLD,
+        value,
+    }
+}
+
+pub fn italic<T: Display>(value: T) -> Styled<T> {
+    Styled {
+        style: ANSI_ITALIC,
+        value,
+    }
+}
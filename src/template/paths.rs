@@ -0,0 +1,114 @@
+/// Resolution of paths inside the project, independent of the current working directory.
+use std::env;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use crate::template::{current_year, Day, Year};
+
+/// Environment variable that overrides the project root, see [`set_root`].
+pub const ROOT_ENV: &str = "AOC_ROOT";
+
+/// The root directory of the project, i.e. the directory containing `Cargo.toml` and `data/`.
+///
+/// Resolved once per process, in order of precedence:
+///  1. the `AOC_ROOT` environment variable.
+///  2. the `CARGO_MANIFEST_DIR` environment variable, set by `cargo run` and `cargo test`.
+///  3. the closest ancestor of the current directory that contains a `Cargo.toml`.
+///  4. the directory the project was compiled in.
+pub fn root() -> &'static Path {
+    static ROOT: OnceLock<PathBuf> = OnceLock::new();
+
+    ROOT.get_or_init(|| {
+        env::var_os(ROOT_ENV)
+            .or_else(|| env::var_os("CARGO_MANIFEST_DIR"))
+            .map(PathBuf::from)
+            .or_else(|| find_root_from(&env::current_dir().ok()?))
+            .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")))
+    })
+}
+
+/// Override the project root for this process and all child processes spawned afterwards.
+/// Needs to be called before the root is first resolved and before any other threads are spawned.
+pub fn set_root(root: &Path) {
+    // child processes may run in a different directory, pass on an absolute path.
+    let root = std::path::absolute(root).unwrap_or_else(|_| root.to_path_buf());
+    env::set_var(ROOT_ENV, root);
+}
+
+/// Walk up from `start` to the closest directory that contains a `Cargo.toml`.
+fn find_root_from(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .find(|dir| dir.join("Cargo.toml").is_file())
+        .map(Path::to_path_buf)
+}
+
+/// Path of the data directory of a year, e.g. `data/2023`.
+pub fn year_dir(year: Year) -> PathBuf {
+    root().join("data").join(year.to_string())
+}
+
+/// Path of a file in the data directory of the current year, e.g. `data/2023/timings.json`.
+pub fn data_file(name: &str) -> PathBuf {
+    year_dir(current_year()).join(name)
+}
+
+/// Path of a subfolder of the data directory of the current year, e.g. `data/2023/inputs`.
+pub fn data_dir(folder: &str) -> PathBuf {
+    year_dir(current_year()).join(folder)
+}
+
+/// Name of the solution bin of a day in the current year, e.g. `2023_01`.
+pub fn bin_name(day: Day) -> String {
+    format!("{}_{day}", current_year())
+}
+
+/// Path of the solution bin of a day, relative to the project root.
+pub fn relative_bin_path(day: Day) -> PathBuf {
+    Path::new("src")
+        .join("bin")
+        .join(format!("{}.rs", bin_name(day)))
+}
+
+/// Path of the solution bin of a day.
+pub fn bin_path(day: Day) -> PathBuf {
+    root().join(relative_bin_path(day))
+}
+
+/// Path of the library module that contains the solutions of a year, e.g. `src/days/y2023`,
+/// see [`Year::module_name`].
+pub fn year_module_dir(year: Year) -> PathBuf {
+    root().join("src").join("days").join(year.module_name())
+}
+
+/// Path of the library module that contains the solution of a day in the current year,
+/// e.g. `src/days/y2023/day01.rs`.
+pub fn day_module_path(day: Day) -> PathBuf {
+    year_module_dir(current_year()).join(format!("day{day}.rs"))
+}
+
+/// Path of the module that declares all year modules of the library.
+pub fn days_mod_path() -> PathBuf {
+    root().join("src").join("days").join("mod.rs")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::find_root_from;
+    use std::{env, fs};
+
+    #[test]
+    fn finds_root_from_subdirectory() {
+        let root = env::temp_dir().join(format!("aoc-paths-{}", std::process::id()));
+        let nested = root.join("src").join("bin");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(root.join("Cargo.toml"), "").unwrap();
+
+        let found = find_root_from(&nested);
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(found, Some(root));
+    }
+}
@@ -0,0 +1,140 @@
+/// Cargo build profiles that solutions can be compiled and benchmarked with.
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// A named cargo profile, e.g. `release` or one of the custom profiles in `Cargo.toml`.
+///
+/// The `native` profile is special-cased: it inherits `release` and is built with
+/// `-C target-cpu=native`, which can not be expressed in `Cargo.toml` on stable.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Profile(String);
+
+impl Profile {
+    /// The profile used for debug builds.
+    pub fn dev() -> Self {
+        Self("dev".into())
+    }
+
+    /// The profile used by `--release` and for the benchmarks stored in the readme.
+    pub fn release() -> Self {
+        Self("release".into())
+    }
+
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+
+    /// Whether timings for this profile are the ones stored in `data/<year>/timings.json`.
+    pub fn is_default(&self) -> bool {
+        self.0 == "release"
+    }
+
+    /// Arguments that select this profile for a cargo invocation.
+    pub fn cargo_args(&self) -> Vec<String> {
+        match self.0.as_str() {
+            "dev" => vec![],
+            "release" => vec!["--release".into()],
+            name => vec!["--profile".into(), name.into()],
+        }
+    }
+
+    /// Additional `RUSTFLAGS` that need to be set when building with this profile.
+    pub fn rustflags(&self) -> Option<&'static str> {
+        match self.0.as_str() {
+            "native" => Some("-C target-cpu=native"),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Profile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Profile {
+    type Err = ProfileFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim();
+
+        if !name.starts_with(|c: char| c.is_ascii_alphanumeric())
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(ProfileFromStrError(name.into()));
+        }
+
+        Ok(Self(name.into()))
+    }
+}
+
+/// Parses a comma-separated list of profiles, e.g. `release,release-lto,native`.
+pub fn parse_profiles(s: &str) -> Result<Vec<Profile>, ProfileFromStrError> {
+    let mut profiles: Vec<Profile> = vec![];
+
+    for profile in s.split(',').map(Profile::from_str) {
+        let profile = profile?;
+        if !profiles.contains(&profile) {
+            profiles.push(profile);
+        }
+    }
+
+    Ok(profiles)
+}
+
+/// An error which can be returned when parsing a [`Profile`].
+#[derive(Debug)]
+pub struct ProfileFromStrError(String);
+
+impl Error for ProfileFromStrError {}
+
+impl Display for ProfileFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid cargo profile name `{}`", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_profiles, Profile};
+
+    #[test]
+    fn parses_profile_lists() {
+        let profiles = parse_profiles("release,release-lto,native").unwrap();
+        assert_eq!(
+            profiles.iter().map(Profile::name).collect::<Vec<_>>(),
+            vec!["release", "release-lto", "native"]
+        );
+    }
+
+    #[test]
+    fn deduplicates_profiles() {
+        let profiles = parse_profiles("release, release").unwrap();
+        assert_eq!(profiles, vec![Profile::release()]);
+    }
+
+    #[test]
+    fn rejects_invalid_profiles() {
+        assert!(parse_profiles("release,").is_err());
+        assert!(parse_profiles("--release").is_err());
+        assert!(parse_profiles("rel ease").is_err());
+    }
+
+    #[test]
+    fn maps_profiles_to_cargo_args() {
+        assert!(Profile::dev().cargo_args().is_empty());
+        assert_eq!(Profile::release().cargo_args(), vec!["--release"]);
+        assert_eq!(
+            "native".parse::<Profile>().unwrap().cargo_args(),
+            vec!["--profile", "native"]
+        );
+    }
+}
@@ -0,0 +1,326 @@
+/// Parsing of the puzzle descriptions that `cargo download` saves to `data/<year>/puzzles`.
+use std::{fs, io, path::PathBuf, sync::OnceLock};
+
+use regex::Regex;
+
+use crate::template::{paths, Day};
+
+/// Path of the puzzle description of a day, e.g. `data/2023/puzzles/01.md`.
+pub fn puzzle_path(day: Day) -> PathBuf {
+    paths::data_dir("puzzles").join(format!("{day}.md"))
+}
+
+/// Read the puzzle description of a day.
+pub fn read_puzzle(day: Day) -> Result<String, io::Error> {
+    fs::read_to_string(puzzle_path(day))
+}
+
+/// An example input found in a puzzle description, with the answers that the description gives
+/// for it. Extracted with heuristics, so it needs to be confirmed by hand.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExtractedExample {
+    pub input: String,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+impl ExtractedExample {
+    fn answer_mut(&mut self, part: u8) -> &mut Option<String> {
+        if part == 1 {
+            &mut self.part_1
+        } else {
+            &mut self.part_2
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Segment {
+    Code(String),
+    Text(String),
+    PartTwo,
+}
+
+/// Split a puzzle description into code blocks and the text between them. Code blocks can be
+/// fenced with backticks or be raw `<pre><code>` blocks.
+fn segments(markdown: &str) -> Vec<Segment> {
+    let mut segments = vec![];
+    let mut text = String::new();
+    let mut lines = markdown.lines();
+
+    while let Some(line) = lines.next() {
+        if line.contains("--- Part Two ---") {
+            flush_text(&mut text, &mut segments);
+            segments.push(Segment::PartTwo);
+        } else if line.trim_start().starts_with("```") {
+            flush_text(&mut text, &mut segments);
+            let code: Vec<&str> = lines
+                .by_ref()
+                .take_while(|l| !l.trim_start().starts_with("```"))
+                .collect();
+            segments.push(Segment::Code(code.join("\n") + "\n"));
+        } else if let Some((before, rest)) = line.split_once("<pre><code>") {
+            text.push_str(before);
+            flush_text(&mut text, &mut segments);
+
+            let mut code = String::new();
+            let mut current = rest;
+            loop {
+                if let Some((inner, after)) = current.split_once("</code></pre>") {
+                    code.push_str(inner);
+                    text.push_str(after);
+                    break;
+                }
+                code.push_str(current);
+                code.push('\n');
+                match lines.next() {
+                    Some(next) => current = next,
+                    None => break,
+                }
+            }
+
+            let mut code = decode_entities(&code);
+            if !code.ends_with('\n') {
+                code.push('\n');
+            }
+            segments.push(Segment::Code(code));
+        } else {
+            text.push_str(line);
+            text.push('\n');
+        }
+    }
+
+    flush_text(&mut text, &mut segments);
+    segments
+}
+
+fn flush_text(text: &mut String, segments: &mut Vec<Segment>) {
+    if !text.trim().is_empty() {
+        segments.push(Segment::Text(std::mem::take(text)));
+    }
+    text.clear();
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// The last emphasized code span of a text, which is how puzzle descriptions highlight the answer
+/// for an example, e.g. `<code><em>142</em></code>` or `` *`142`* `` once converted to markdown.
+fn find_answer(text: &str) -> Option<String> {
+    static ANSWER: OnceLock<Regex> = OnceLock::new();
+    let re = ANSWER.get_or_init(|| {
+        Regex::new(concat!(
+            r"<code><em>([^<]+)</em></code>|<em><code>([^<]+)</code></em>",
+            r"|[*_]{1,2}`([^`]+)`[*_]{1,2}|`[*_]{1,2}([^`*_]+)[*_]{1,2}`",
+        ))
+        .expect("regex is correct")
+    });
+
+    let captures = re.captures_iter(text).last()?;
+    let answer = captures.iter().skip(1).flatten().next()?.as_str();
+    Some(decode_entities(answer.trim()))
+}
+
+/// Find the example inputs of a puzzle description and the answers given for them.
+///
+/// An answer belongs to the closest code block before it. Answers of part two that follow no code
+/// block of part two belong to the last example of part one that has an answer, as part two often
+/// reuses the example. If no answer is found, the first code block is returned on its own.
+pub fn extract_examples(markdown: &str) -> Vec<ExtractedExample> {
+    let mut examples: Vec<ExtractedExample> = vec![];
+    let mut current: Option<usize> = None;
+    let mut part = 1;
+
+    for segment in segments(markdown) {
+        match segment {
+            Segment::Code(input) if input.trim().is_empty() => {}
+            Segment::Code(input) => {
+                let index = examples
+                    .iter()
+                    .position(|e| e.input == input)
+                    .unwrap_or_else(|| {
+                        examples.push(ExtractedExample {
+                            input,
+                            ..Default::default()
+                        });
+                        examples.len() - 1
+                    });
+                current = Some(index);
+            }
+            Segment::PartTwo => {
+                part = 2;
+                current = examples.iter().rposition(|e| e.part_1.is_some());
+            }
+            Segment::Text(text) => {
+                let (Some(index), Some(answer)) = (current, find_answer(&text)) else {
+                    continue;
+                };
+                examples[index].answer_mut(part).get_or_insert(answer);
+            }
+        }
+    }
+
+    let first = examples.first().cloned();
+    examples.retain(|e| e.part_1.is_some() || e.part_2.is_some());
+
+    match (examples.is_empty(), first) {
+        (true, Some(first)) => vec![first],
+        _ => examples,
+    }
+}
+
+/// The answers of the parts a user has solved, in order of the parts. Puzzle descriptions show
+/// them below each solved part as "Your puzzle answer was `X`."
+pub fn find_puzzle_answers(markdown: &str) -> Vec<String> {
+    static PUZZLE_ANSWER: OnceLock<Regex> = OnceLock::new();
+    let re = PUZZLE_ANSWER.get_or_init(|| {
+        Regex::new(r"Your puzzle answer was\s*(?:<code>([^<]*)</code>|`([^`]*)`)")
+            .expect("regex is correct")
+    });
+
+    re.captures_iter(markdown)
+        .filter_map(|captures| {
+            let answer = captures.iter().skip(1).flatten().next()?.as_str();
+            Some(decode_entities(answer.trim()))
+        })
+        .collect()
+}
+
+/// The description of part two, from its heading up to the answer of part two or the end of the
+/// description. `None` if part two is not unlocked yet.
+pub fn part_two_section(markdown: &str) -> Option<&str> {
+    let start = markdown.find("--- Part Two ---")?;
+    // include the markup of the heading, e.g. `## ` or `<h2>`.
+    let start = markdown[..start].rfind('\n').map_or(0, |i| i + 1);
+    let section = &markdown[start..];
+
+    let end = section
+        .find("Your puzzle answer was")
+        .map_or(section.len(), |end| {
+            section[..end].rfind('\n').map_or(end, |i| i + 1)
+        });
+
+    Some(section[..end].trim_end())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        extract_examples, find_answer, find_puzzle_answers, part_two_section, ExtractedExample,
+    };
+
+    const MARKDOWN: &str = "\
+## --- Day 1: Trebuchet?! ---
+
+For example:
+
+```
+1abc2
+pqr3stu8vwx
+```
+
+In this example, the calibration values are `12` and `38`. Adding these together produces `*50*`.
+
+What is the sum of all of the calibration values?
+
+## --- Part Two ---
+
+For example:
+
+```
+two1nine
+eightwothree
+```
+
+Adding these together produces *`112`*.
+";
+
+    fn example(input: &str, part_1: Option<&str>, part_2: Option<&str>) -> ExtractedExample {
+        ExtractedExample {
+            input: input.into(),
+            part_1: part_1.map(Into::into),
+            part_2: part_2.map(Into::into),
+        }
+    }
+
+    #[test]
+    fn finds_emphasized_answers() {
+        assert_eq!(find_answer("values are `12` and `38`."), None);
+        assert_eq!(
+            find_answer("produces `*50*` and `**7**`."),
+            Some("7".into())
+        );
+        assert_eq!(
+            find_answer("is <code><em>a &lt; b</em></code>."),
+            Some("a < b".into())
+        );
+        assert_eq!(find_answer("is <em><code>4</code></em>."), Some("4".into()));
+    }
+
+    #[test]
+    fn extracts_examples_per_part() {
+        assert_eq!(
+            extract_examples(MARKDOWN),
+            [
+                example("1abc2\npqr3stu8vwx\n", Some("50"), None),
+                example("two1nine\neightwothree\n", None, Some("112")),
+            ]
+        );
+    }
+
+    #[test]
+    fn reuses_part_one_example() {
+        let html = "<p>For example:</p>\n<pre><code>1 &lt; 2\n3\n</code></pre>\n\
+            <p>The answer is <code><em>4</em></code>.</p>\n\
+            <h2 id=\"part2\">--- Part Two ---</h2>\n\
+            <p>In the same example, the answer is <code><em>6</em></code>.</p>\n";
+
+        assert_eq!(
+            extract_examples(html),
+            [example("1 < 2\n3\n", Some("4"), Some("6"))]
+        );
+    }
+
+    #[test]
+    fn falls_back_to_first_code_block() {
+        assert_eq!(
+            extract_examples("```\na\n```\nNo answer.\n```\nb\n```\n"),
+            [example("a\n", None, None)]
+        );
+    }
+
+    #[test]
+    fn finds_puzzle_answers() {
+        assert!(find_puzzle_answers(MARKDOWN).is_empty());
+
+        let solved = "Your puzzle answer was `54304`.\n\n## --- Part Two ---\n\n\
+            Your puzzle answer was `54418`.\n\nBoth parts of this puzzle are complete!";
+        assert_eq!(find_puzzle_answers(solved), ["54304", "54418"]);
+
+        let html = "<p>Your puzzle answer was <code>a&amp;b</code>.</p>";
+        assert_eq!(find_puzzle_answers(html), ["a&b"]);
+    }
+
+    #[test]
+    fn finds_part_two_section() {
+        assert_eq!(part_two_section("## --- Day 1 ---\nOnly part one.\n"), None);
+        assert_eq!(
+            part_two_section(MARKDOWN),
+            Some("## --- Part Two ---\n\nFor example:\n\n```\ntwo1nine\neightwothree\n```\n\nAdding these together produces *`112`*.")
+        );
+
+        let solved = "Your puzzle answer was `1`.\n\n## --- Part Two ---\n\nMore.\n\n\
+            Your puzzle answer was `2`.\n";
+        assert_eq!(
+            part_two_section(solved),
+            Some("## --- Part Two ---\n\nMore.")
+        );
+    }
+}
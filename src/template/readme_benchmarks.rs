@@ -1,11 +1,20 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::{fmt::Display, fs, io};
 
 use crate::template::timings::Timings;
-use crate::template::Day;
+use crate::template::{current_year, paths, Day, Year};
 
-static MARKER: &str = "<!--- benchmarking table --->";
+/// Start of the markers around the benchmark tables, followed by the year of the table.
+static MARKER_PREFIX: &str = "<!--- benchmarking table ";
+
+/// Marker of a table without a year, which is taken over by the first year that updates it.
+static LEGACY_MARKER: &str = "<!--- benchmarking table --->";
+
+/// The marker that surrounds the benchmark table of a year.
+fn marker(year: Year) -> String {
+    format!("{MARKER_PREFIX}{year} --->")
+}
 
 #[derive(Debug)]
 pub enum Error {
@@ -19,18 +28,57 @@ impl From<std::io::Error> for Error {
     }
 }
 
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse README: {e}"),
+            Error::IO(e) => write!(f, "could not read or write README: {e}"),
+        }
+    }
+}
+
 pub struct TablePosition {
     pos_start: usize,
     pos_end: usize,
+    /// Whether the table is new and inserted after the tables of other years.
+    is_new: bool,
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(year: Year, day: Day) -> String {
+    format!("./src/bin/{year}_{day}.rs")
+}
+
+/// Locate the table of a year. Falls back to a table without a year, or a new table after the
+/// tables of other years.
+fn locate_table(readme: &str, year: Year) -> Result<TablePosition, Error> {
+    if readme.contains(&marker(year)) {
+        return locate_marked_table(readme, &marker(year));
+    }
+
+    if readme.contains(LEGACY_MARKER) {
+        return locate_marked_table(readme, LEGACY_MARKER);
+    }
+
+    match readme.rfind(MARKER_PREFIX) {
+        Some(pos) => {
+            let end = readme[pos..]
+                .find("-->")
+                .map(|offset| pos + offset + "-->".len())
+                .ok_or_else(|| Error::Parser("Could not find table end position.".into()))?;
+
+            Ok(TablePosition {
+                pos_start: end,
+                pos_end: end,
+                is_new: true,
+            })
+        }
+        None => Err(Error::Parser("Could not find table start position.".into())),
+    }
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+fn locate_marked_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -48,79 +96,101 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
         .map(|m| m.0 + m.1.len())
         .ok_or_else(|| Error::Parser("Could not find table end position.".into()))?;
 
-    Ok(TablePosition { pos_start, pos_end })
+    Ok(TablePosition {
+        pos_start,
+        pos_end,
+        is_new: false,
+    })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+fn construct_table(prefix: &str, year: Year, timings: Timings, total_millis: f64) -> String {
+    let header = format!("{prefix} {} Benchmarks", year.title());
+    let label = year.event().day_label();
+    let parts = 1..=year.part_count();
+
+    let columns: String = parts
+        .clone()
+        .map(|part| format!(" Part {part} |"))
+        .collect();
 
     let mut lines: Vec<String> = vec![
-        MARKER.into(),
+        marker(year),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        format!("| {label} |{columns}"),
+        format!("|{} :---:  |", " :---: |".repeat(parts.len())),
     ];
 
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
+        let path = get_path_for_bin(year, timing.day);
+        let cells: String = parts
+            .clone()
+            .map(|part| format!(" `{}` |", timing.part(part).unwrap_or("-")))
+            .collect();
+
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+            "| [{label} {}]({path}) |{cells}",
             timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
         ));
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines.push(marker(year));
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+fn update_content(
+    s: &mut String,
+    year: Year,
+    timings: Timings,
+    total_millis: f64,
+) -> Result<(), Error> {
+    let positions = locate_table(s, year)?;
+    let mut table = construct_table("##", year, timings, total_millis);
+    if positions.is_new {
+        table.insert_str(0, "\n\n");
+    }
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
+/// Update the benchmark table of the current year in the readme.
 pub fn update(timings: Timings) -> Result<(), Error> {
-    let path = "README.md";
-    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    let path = paths::root().join("README.md");
+    let mut readme = String::from_utf8_lossy(&fs::read(&path)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, current_year(), timings, total_millis)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, MARKER};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use super::{marker, update_content, LEGACY_MARKER};
+    use crate::{day, template::timings::Timing, template::timings::Timings, year};
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    parts: vec![Some("10ms".into()), Some("20ms".into())],
                     total_nanos: 3e+10,
+                    scheduling: None,
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    parts: vec![Some("30ms".into()), Some("40ms".into())],
                     total_nanos: 7e+10,
+                    scheduling: None,
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: Some("50ms".into()),
+                    parts: vec![Some("40ms".into()), Some("50ms".into())],
                     total_nanos: 9e+10,
+                    scheduling: None,
                 },
             ],
         }
@@ -130,50 +200,93 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
-        let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        let marker = marker(year!(2023));
+        let mut s = format!("{} {} {}", marker, marker, marker);
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
-        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.contains("## Benchmarks"), true);
+        let marker = marker(year!(2023));
+        let mut s = format!("foo\nbar\n{}{}\nbaz", marker, marker);
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
+        assert_eq!(s.contains("## 2023 Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
-        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
-        assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
+        let marker = marker(year!(2023));
+        let mut s = format!("foo\nbar\n{}{}\nbaz", marker, marker);
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
+        assert_eq!(s.matches(&marker).collect::<Vec<&str>>().len(), 2);
+        assert_eq!(
+            s.matches("## 2023 Benchmarks").collect::<Vec<&str>>().len(),
+            1
+        );
+    }
+
+    #[test]
+    fn takes_over_legacy_table() {
+        let mut s = format!("foo\n{}{}\nbaz", LEGACY_MARKER, LEGACY_MARKER);
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
+        assert!(!s.contains(LEGACY_MARKER));
+        assert_eq!(s.matches(&marker(year!(2023))).count(), 2);
+    }
+
+    #[test]
+    fn adds_tables_of_other_years() {
+        let marker = marker(year!(2022));
+        let mut s = format!("foo\n{}{}\nbaz", marker, marker);
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
+
+        assert_eq!(s.matches(&marker).count(), 2);
+        assert_eq!(s.matches("## 2023 Benchmarks").count(), 1);
+        assert!(s.find(&marker).unwrap() < s.find("## 2023 Benchmarks").unwrap());
+        assert!(s.ends_with("<!--- benchmarking table 2023 --->\nbaz"));
+    }
+
+    #[test]
+    fn adds_columns_for_parts_of_the_event() {
+        let marker = marker(year!(ec 2024));
+        let mut s = format!("foo\n{}\n{}\nbaz", marker, marker);
+        let mut timings = get_mock_timings();
+        timings.data[0].parts.push(Some("30ms".into()));
+        update_content(&mut s, year!(ec 2024), timings, 190.0).unwrap();
+
+        assert!(s.contains("## Everybody Codes 2024 Benchmarks"));
+        assert!(s.contains("| Quest | Part 1 | Part 2 | Part 3 |"));
+        assert!(s.contains("| :---: | :---: | :---: | :---:  |"));
+        assert!(s.contains("| [Quest 1](./src/bin/ec2024_01.rs) | `10ms` | `20ms` | `30ms` |"));
+        assert!(s.contains("| [Quest 2](./src/bin/ec2024_02.rs) | `30ms` | `40ms` | `-` |"));
     }
 
     #[test]
     fn format_benchmarks() {
-        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        let marker = marker(year!(2023));
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", marker, marker);
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
         let expected = [
             "foo",
             "bar",
-            "<!--- benchmarking table --->",
-            "## Benchmarks",
+            "<!--- benchmarking table 2023 --->",
+            "## 2023 Benchmarks",
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/2023_01.rs) | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2023_02.rs) | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2023_04.rs) | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
-            "<!--- benchmarking table --->",
+            "<!--- benchmarking table 2023 --->",
             "baz",
         ]
         .join("\n");
@@ -0,0 +1,56 @@
+/// Registry of the days compiled into the library, so that benches and tools can run every day
+/// without knowing the signatures of its solution.
+use crate::template::{Day, Year};
+
+/// Entry points of the solution of a day, declared by `solution!(lib <day>)`.
+///
+/// The results of `parse` and the `run` function of each part are passed to
+/// [`std::hint::black_box`] and discarded, so that the functions can be benched without being
+/// optimized out. The `answer` function of a part returns its answer formatted with `Display`
+/// instead.
+#[derive(Clone, Copy, Debug)]
+pub struct Solution {
+    pub year: Year,
+    pub day: Day,
+    /// Parses the input, only present if the day registered a parser with `solution!(lib <day>, parse: <fn>)`.
+    pub parse: Option<fn(&str)>,
+    /// The parts the day implements, in order.
+    pub parts: &'static [Part],
+}
+
+/// Entry points of a part of a [`Solution`].
+#[derive(Clone, Copy, Debug)]
+pub struct Part {
+    /// The number of the part, starting at 1.
+    pub number: u8,
+    pub run: fn(&str),
+    pub answer: fn(&str) -> Option<String>,
+}
+
+impl Solution {
+    /// Entry point of a part, if the day implements it.
+    pub fn part(&self, part: u8) -> Option<fn(&str)> {
+        self.find_part(part).map(|p| p.run)
+    }
+
+    /// Entry point of a part that returns its answer, if the day implements it.
+    pub fn answer(&self, part: u8) -> Option<fn(&str) -> Option<String>> {
+        self.find_part(part).map(|p| p.answer)
+    }
+
+    fn find_part(&self, part: u8) -> Option<&Part> {
+        self.parts.iter().find(|p| p.number == part)
+    }
+}
+
+/// The registered solutions of all years, in order.
+pub fn all() -> impl Iterator<Item = &'static Solution> {
+    crate::days::SOLUTIONS
+        .iter()
+        .flat_map(|solutions| solutions.iter())
+}
+
+/// The registered solution of a day of a year, if any.
+pub fn find(year: Year, day: Day) -> Option<&'static Solution> {
+    all().find(|s| s.year == year && s.day == day)
+}
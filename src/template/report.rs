@@ -0,0 +1,494 @@
+/// Results of running solutions and their machine-readable representations.
+use std::{collections::HashMap, fmt::Display, fs, io, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::answers::Answers;
+use crate::template::run_multi::child_commands::{parse_duration, ParsedPart};
+use crate::template::{current_year, Day};
+
+/// Outcome of running a part (or all parts of a day).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Status {
+    Solved,
+    Unsolved,
+    Mismatched,
+    Failed,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Status::Solved => "solved",
+            Status::Unsolved => "unsolved",
+            Status::Mismatched => "mismatched",
+            Status::Failed => "failed",
+        })
+    }
+}
+
+/// Result of checking an answer against the known answers in `data/<year>/answers.json`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verification {
+    Correct,
+    Incorrect { expected: String },
+    Unknown,
+}
+
+impl Display for Verification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Verification::Correct => "correct",
+            Verification::Incorrect { .. } => "incorrect",
+            Verification::Unknown => "unknown",
+        })
+    }
+}
+
+/// Represents the result of running a single part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartResult {
+    pub part: u8,
+    pub answer: Option<String>,
+    pub time: Option<String>,
+    pub status: Status,
+    pub verification: Verification,
+}
+
+/// Represents the result of running all parts of a day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DayResult {
+    pub day: Day,
+    pub parts: Vec<PartResult>,
+}
+
+impl DayResult {
+    /// Builds the result of a day of the current year from the parts printed by its solution.
+    /// `success` is `false` if the solution exited with an error, e.g. because it panicked.
+    pub fn new(day: Day, parsed: &[ParsedPart], success: bool, answers: &Answers) -> Self {
+        let parts = (1..=current_year().part_count())
+            .map(|part| {
+                let parsed = parsed.iter().find(|p| p.part == part);
+                let answer = parsed.and_then(|p| p.answer.clone());
+
+                let verification = match (&answer, answers.get(day, part)) {
+                    (Some(answer), Some(expected)) if answer == expected => Verification::Correct,
+                    (_, Some(expected)) => Verification::Incorrect {
+                        expected: expected.into(),
+                    },
+                    (_, None) => Verification::Unknown,
+                };
+
+                let status = match (&answer, &verification) {
+                    (None, _) if !success => Status::Failed,
+                    // a known answer that is no longer produced is a regression, not a skip.
+                    (_, Verification::Incorrect { .. }) => Status::Mismatched,
+                    (None, _) => Status::Unsolved,
+                    (Some(_), _) => Status::Solved,
+                };
+
+                PartResult {
+                    part,
+                    answer,
+                    time: parsed.and_then(|p| p.time.clone()),
+                    status,
+                    verification,
+                }
+            })
+            .collect();
+
+        Self { day, parts }
+    }
+
+    /// Result of a day that has not been scaffolded yet.
+    pub fn unsolved(day: Day) -> Self {
+        Self::new(day, &[], true, &Answers::default())
+    }
+
+    /// The most severe status of all parts.
+    pub fn status(&self) -> Status {
+        self.parts
+            .iter()
+            .map(|p| p.status)
+            .max()
+            .unwrap_or(Status::Unsolved)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Answers longer than this are truncated in the summary table.
+const MAX_ANSWER_WIDTH: usize = 16;
+
+fn format_answer(answer: Option<&str>) -> String {
+    match answer {
+        None => "-".into(),
+        Some(answer) if answer.contains('\n') => format!("▼ ({} lines)", answer.lines().count()),
+        Some(answer) if answer.chars().count() > MAX_ANSWER_WIDTH => {
+            let truncated: String = answer.chars().take(MAX_ANSWER_WIDTH - 1).collect();
+            format!("{truncated}…")
+        }
+        Some(answer) => answer.into(),
+    }
+}
+
+/// Format an aligned table with one row per day, followed by the total time of all parts.
+/// The table has an answer and a time column for every part of the current year.
+pub fn format_summary(results: &[DayResult]) -> String {
+    let year = current_year();
+    let parts = 1..=year.part_count();
+
+    let mut header = vec![year.event().day_label().to_string()];
+    header.extend(parts.clone().map(|part| format!("Part {part}")));
+    header.extend(parts.clone().map(|part| format!("Time {part}")));
+    header.push("Status".into());
+
+    let mut rows: Vec<Vec<String>> = vec![header];
+
+    for result in results {
+        let part = |part: u8| result.parts.iter().find(|p| p.part == part);
+
+        let mut row = vec![result.day.to_string()];
+        row.extend(
+            parts
+                .clone()
+                .map(|p| format_answer(part(p).and_then(|p| p.answer.as_deref()))),
+        );
+        row.extend(parts.clone().map(|p| {
+            part(p)
+                .and_then(|p| p.time.clone())
+                .unwrap_or_else(|| "-".into())
+        }));
+        row.push(result.status().to_string());
+        rows.push(row);
+    }
+
+    let widths: Vec<usize> = (0..rows[0].len())
+        .map(|i| rows.iter().map(|r| r[i].chars().count()).max().unwrap_or(0))
+        .collect();
+
+    let total_nanos: f64 = results
+        .iter()
+        .flat_map(|r| &r.parts)
+        .filter_map(|p| p.time.as_deref().and_then(parse_duration))
+        .sum();
+
+    let mut lines: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect::<Vec<_>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        })
+        .collect();
+
+    lines.push(format!("Total: {:.2}ms", total_nanos / 1_000_000_f64));
+    lines.join("\n")
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Machine-readable output formats for the results of `cargo all`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReportFormat {
+    Json,
+    JUnit,
+}
+
+impl FromStr for ReportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(ReportFormat::Json),
+            "junit" => Ok(ReportFormat::JUnit),
+            _ => Err(format!(
+                "unknown report format `{s}`, expecting `json` or `junit`"
+            )),
+        }
+    }
+}
+
+/// Write a report of `results` to `path`.
+pub fn write_report(results: &[DayResult], format: ReportFormat, path: &str) -> io::Result<()> {
+    let content = match format {
+        ReportFormat::Json => to_json(results)
+            .format()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?,
+        ReportFormat::JUnit => to_junit(results),
+    };
+
+    fs::write(path, content)
+}
+
+fn optional_string(value: Option<&String>) -> JsonValue {
+    value.map_or(JsonValue::Null, |v| JsonValue::String(v.clone()))
+}
+
+fn to_json(results: &[DayResult]) -> JsonValue {
+    let days = results
+        .iter()
+        .map(|result| {
+            let parts = result
+                .parts
+                .iter()
+                .map(|part| {
+                    let expected = match &part.verification {
+                        Verification::Incorrect { expected } => Some(expected),
+                        _ => None,
+                    };
+
+                    let mut map: HashMap<String, JsonValue> = HashMap::new();
+                    map.insert("part".into(), JsonValue::Number(part.part.into()));
+                    map.insert("answer".into(), optional_string(part.answer.as_ref()));
+                    map.insert("status".into(), JsonValue::String(part.status.to_string()));
+                    map.insert("time".into(), optional_string(part.time.as_ref()));
+                    map.insert(
+                        "time_nanos".into(),
+                        part.time
+                            .as_deref()
+                            .and_then(parse_duration)
+                            .map_or(JsonValue::Null, JsonValue::Number),
+                    );
+                    map.insert(
+                        "verification".into(),
+                        JsonValue::String(part.verification.to_string()),
+                    );
+                    map.insert("expected".into(), optional_string(expected));
+                    JsonValue::Object(map)
+                })
+                .collect();
+
+            let mut map: HashMap<String, JsonValue> = HashMap::new();
+            map.insert("day".into(), JsonValue::String(result.day.to_string()));
+            map.insert(
+                "status".into(),
+                JsonValue::String(result.status().to_string()),
+            );
+            map.insert("parts".into(), JsonValue::Array(parts));
+            JsonValue::Object(map)
+        })
+        .collect();
+
+    let mut map: HashMap<String, JsonValue> = HashMap::new();
+    map.insert("days".into(), JsonValue::Array(days));
+    JsonValue::Object(map)
+}
+
+fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn to_junit(results: &[DayResult]) -> String {
+    let count = |status: Status| {
+        results
+            .iter()
+            .flat_map(|r| &r.parts)
+            .filter(|p| p.status == status)
+            .count()
+    };
+
+    let failures = count(Status::Mismatched) + count(Status::Failed);
+    let skipped = count(Status::Unsolved);
+    let tests: usize = results.iter().map(|r| r.parts.len()).sum();
+
+    let mut lines: Vec<String> = vec![
+        r#"<?xml version="1.0" encoding="UTF-8"?>"#.into(),
+        format!(
+            r#"<testsuites name="advent_of_code" tests="{tests}" failures="{failures}" skipped="{skipped}">"#
+        ),
+    ];
+
+    for result in results {
+        let day_failures = result
+            .parts
+            .iter()
+            .filter(|p| matches!(p.status, Status::Mismatched | Status::Failed))
+            .count();
+        let day_skipped = result
+            .parts
+            .iter()
+            .filter(|p| p.status == Status::Unsolved)
+            .count();
+
+        lines.push(format!(
+            r#"  <testsuite name="Day {}" tests="{}" failures="{day_failures}" skipped="{day_skipped}">"#,
+            result.day,
+            result.parts.len()
+        ));
+
+        for part in &result.parts {
+            let seconds = part.time.as_deref().and_then(parse_duration).unwrap_or(0.0) / 1e9;
+
+            lines.push(format!(
+                r#"    <testcase classname="day{}" name="part_{}" time="{seconds:.9}">"#,
+                result.day, part.part
+            ));
+
+            let answer = escape_xml(part.answer.as_deref().unwrap_or("-"));
+
+            match (&part.status, &part.verification) {
+                (Status::Failed, _) => {
+                    lines
+                        .push(r#"      <failure message="solution exited with an error"/>"#.into());
+                }
+                (Status::Mismatched, Verification::Incorrect { expected }) => {
+                    lines.push(format!(
+                        r#"      <failure message="expected {}, got {answer}"/>"#,
+                        escape_xml(expected)
+                    ));
+                }
+                (Status::Unsolved, _) => {
+                    lines.push(r#"      <skipped message="not solved"/>"#.into());
+                }
+                _ => {
+                    lines.push(format!("      <system-out>{answer}</system-out>"));
+                }
+            }
+
+            lines.push("    </testcase>".into());
+        }
+
+        lines.push("  </testsuite>".into());
+    }
+
+    lines.push("</testsuites>".into());
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_summary, to_json, to_junit, DayResult, Status, Verification};
+    use crate::{
+        day,
+        template::{answers::Answers, run_multi::child_commands::ParsedPart},
+    };
+
+    fn get_mock_results() -> Vec<DayResult> {
+        let mut answers = Answers::default();
+        answers.set(day!(1), 1, "142");
+        answers.set(day!(1), 2, "281");
+
+        vec![
+            DayResult::new(
+                day!(1),
+                &[
+                    ParsedPart {
+                        part: 1,
+                        answer: Some("142".into()),
+                        time: Some("1.0ms".into()),
+                    },
+                    ParsedPart {
+                        part: 2,
+                        answer: Some("280".into()),
+                        time: Some("2.0ms".into()),
+                    },
+                ],
+                true,
+                &answers,
+            ),
+            DayResult::new(
+                day!(2),
+                &[ParsedPart {
+                    part: 1,
+                    answer: Some("8".into()),
+                    time: Some("1.0µs".into()),
+                }],
+                false,
+                &answers,
+            ),
+            DayResult::unsolved(day!(3)),
+        ]
+    }
+
+    #[test]
+    fn verifies_parts() {
+        let results = get_mock_results();
+
+        assert_eq!(results[0].parts[0].verification, Verification::Correct);
+        assert_eq!(results[0].parts[0].status, Status::Solved);
+        assert_eq!(
+            results[0].parts[1].verification,
+            Verification::Incorrect {
+                expected: "281".into()
+            }
+        );
+        assert_eq!(results[0].status(), Status::Mismatched);
+
+        assert_eq!(results[1].parts[0].verification, Verification::Unknown);
+        assert_eq!(results[1].parts[0].status, Status::Solved);
+        assert_eq!(results[1].parts[1].status, Status::Failed);
+        assert_eq!(results[1].status(), Status::Failed);
+
+        assert_eq!(results[2].status(), Status::Unsolved);
+    }
+
+    #[test]
+    fn mismatches_missing_known_answers() {
+        let mut answers = Answers::default();
+        answers.set(day!(1), 2, "281");
+
+        let results = vec![DayResult::new(
+            day!(1),
+            &[ParsedPart {
+                part: 1,
+                answer: Some("142".into()),
+                time: Some("1.0ms".into()),
+            }],
+            true,
+            &answers,
+        )];
+
+        assert_eq!(results[0].parts[1].status, Status::Mismatched);
+        assert_eq!(results[0].status(), Status::Mismatched);
+
+        let xml = to_junit(&results);
+        assert!(xml.contains(r#"<failure message="expected 281, got -"/>"#));
+        assert!(!xml.contains("<skipped"));
+    }
+
+    #[test]
+    fn serializes_json_reports() {
+        let json = to_json(&get_mock_results()).stringify().unwrap();
+        assert!(json.contains(r#""status":"mismatched""#));
+        assert!(json.contains(r#""expected":"281""#));
+    }
+
+    #[test]
+    fn serializes_junit_reports() {
+        let xml = to_junit(&get_mock_results());
+        assert!(xml
+            .contains(r#"<testsuites name="advent_of_code" tests="6" failures="2" skipped="2">"#));
+        assert!(xml.contains(r#"<testcase classname="day01" name="part_1" time="0.001000000">"#));
+        assert!(xml.contains(r#"<failure message="expected 281, got 280"/>"#));
+        assert!(xml.contains(r#"<failure message="solution exited with an error"/>"#));
+        assert!(xml.contains(r#"<skipped message="not solved"/>"#));
+    }
+    #[test]
+    fn formats_summary() {
+        let mut results = get_mock_results();
+        results[1].parts[0].answer = Some("1234567890123456789".into());
+        results[1].parts[1].answer = Some("#.\n.#".into());
+
+        assert_eq!(
+            format_summary(&results),
+            [
+                "Day  Part 1            Part 2       Time 1  Time 2  Status",
+                "01   142               280          1.0ms   2.0ms   mismatched",
+                "02   123456789012345…  ▼ (2 lines)  1.0µs   -       failed",
+                "03   -                 -            -       -       unsolved",
+                "Total: 3.00ms",
+            ]
+            .join("\n")
+        );
+    }
+}
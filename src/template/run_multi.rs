@@ -1,48 +1,140 @@
-use std::{collections::HashSet, io};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    io, process,
+};
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{
+    current_year,
+    output::{self, bold},
+    paths,
+    profiles::Profile,
+    scheduling::SchedulingOptions,
+    sorted_days, Day,
+};
 
 use super::{
-    all_days,
+    answers::Answers,
+    report::{format_summary, DayResult},
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
+/// Results of running a set of days.
+pub struct RunSummary {
+    pub days: Vec<DayResult>,
+    /// Benchmark timings, only present for timed runs.
+    pub timings: Option<Timings>,
+}
+
+pub fn run_multi(
+    days_to_run: &HashSet<Day>,
+    profile: &Profile,
+    is_timed: bool,
+    scheduling: &SchedulingOptions,
+) -> RunSummary {
+    let answers = Answers::read_from_file();
+
     let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+    let mut results: Vec<DayResult> = Vec::with_capacity(days_to_run.len());
 
-    let mut need_space = false;
+    // skip days that have not been scaffolded yet.
+    let scaffolded: Vec<Day> = sorted_days(days_to_run)
+        .filter(|day| paths::bin_path(*day).exists())
+        .collect();
 
-    // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
+    let (mut builds, diagnostics) = if scaffolded.is_empty() {
+        (HashMap::new(), vec![])
+    } else {
+        if !output::is_quiet() {
+            println!("Building {} solution(s)...", scaffolded.len());
+        }
+        match child_commands::build_solutions(&scaffolded, profile) {
+            Ok(res) => res,
+            Err(e) => {
+                eprintln!("Failed to build solutions: {e}");
+                process::exit(1);
             }
-            need_space = true;
+        }
+    };
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+    for diagnostic in diagnostics {
+        eprint!("{diagnostic}");
+    }
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    let quiet = output::is_quiet();
+    let label = current_year().event().day_label();
 
-            if output.is_empty() {
-                println!("Not solved.");
+    sorted_days(days_to_run).for_each(|day| {
+        if quiet {
+            println!("{}", bold(format!("{label} {day}")));
+        } else {
+            println!();
+            println!("{}", bold(format!("{label} {day}")));
+            println!("------");
+        }
+
+        let Some(build) = builds.remove(&day) else {
+            if scaffolded.contains(&day) {
+                println!("Failed to compile.");
+                results.push(DayResult::new(day, &[], false, &answers));
             } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
+                println!("Not solved.");
+                results.push(DayResult::unsolved(day));
             }
-        });
+            return;
+        };
 
-    if is_timed {
-        let timings = Timings { data: timings };
-        let total_millis = timings.total_millis();
-        println!(
-            "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
-        );
-        Some(timings)
+        for diagnostic in &build.diagnostics {
+            eprint!("{diagnostic}");
+        }
+
+        let Some(executable) = build.executable else {
+            println!("Failed to compile.");
+            results.push(DayResult::new(day, &[], false, &answers));
+            return;
+        };
+
+        if output::is_verbose() {
+            println!("Running \"{}\"", executable.display());
+        }
+
+        let output = child_commands::run_solution(&executable, is_timed, scheduling).unwrap();
+
+        let parts = child_commands::parse_parts(&output.lines);
+
+        // in quiet mode, the output of the solution is not forwarded, print its answers instead.
+        if quiet {
+            for part in &parts {
+                match &part.answer {
+                    Some(answer) if answer.contains('\n') => {
+                        println!("Part {}: ▼\n{answer}", part.part);
+                    }
+                    Some(answer) => println!("Part {}: {}", part.part, bold(answer)),
+                    None => println!("Part {}: ✖", part.part),
+                }
+            }
+        }
+
+        results.push(DayResult::new(day, &parts, output.success, &answers));
+
+        let val = child_commands::parse_exec_time(&output.lines, day);
+        timings.push(val);
+    });
+
+    if !quiet {
+        println!("\n{}", bold("Summary"));
+        println!("{}", format_summary(&results));
+    }
+
+    let timings = if is_timed {
+        Some(Timings { data: timings })
     } else {
         None
+    };
+
+    RunSummary {
+        days: results,
+        timings,
     }
 }
 
@@ -58,47 +150,187 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::BrokenPipe => write!(f, "could not read output of child process."),
+            Error::IO(e) => write!(f, "could not run child process: {e}"),
+        }
+    }
 }
 
 /// All solutions live in isolated binaries.
 /// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
 pub mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::template::Day;
+    use super::Error;
+    use crate::template::{
+        output, paths, profiles::Profile, runner::BREAKDOWN_PREFIX, scheduling::SchedulingOptions,
+        Day,
+    };
     use std::{
+        collections::HashMap,
         io::{BufRead, BufReader},
-        path::Path,
+        path::{Path, PathBuf},
         process::{Command, Stdio},
         thread,
     };
+    use tinyjson::JsonValue;
+
+    /// Captured stdout of a solution bin.
+    pub struct SolutionOutput {
+        pub lines: Vec<String>,
+        /// `false` if the solution exited with an error, e.g. because it panicked.
+        pub success: bool,
+    }
+
+    /// The outcome of compiling the solution bin of a day.
+    #[derive(Debug, Default)]
+    pub struct Build {
+        /// Path to the compiled executable, `None` if compilation failed.
+        pub executable: Option<PathBuf>,
+        /// Rendered compiler warnings and errors.
+        pub diagnostics: Vec<String>,
+    }
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+    /// Compile the solution bins of the given days with a single `cargo build` invocation.
+    /// With `--keep-going`, a compile error in one bin does not prevent the others from being built.
+    ///
+    /// Returns the build of every day, as well as diagnostics that can not be attributed to a day,
+    /// e.g. errors in the library crate.
+    pub fn build_solutions(
+        days: &[Day],
+        profile: &Profile,
+    ) -> Result<(HashMap<Day, Build>, Vec<String>), Error> {
+        let mut cmd = Command::new("cargo");
+        cmd.current_dir(paths::root()).args([
+            "build",
+            "--quiet",
+            "--keep-going",
+            "--message-format=json",
+        ]);
+
+        for day in days {
+            cmd.arg("--bin").arg(paths::bin_name(*day));
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        cmd.args(profile.cargo_args());
+
+        // solutions are built with the inputs of the current tree if this binary embeds them.
+        if cfg!(feature = "embed-inputs") {
+            cmd.args(["--features", "embed-inputs"]);
+        }
 
-        if is_release {
-            args.push("--release");
+        if let Some(rustflags) = profile.rustflags() {
+            cmd.env("RUSTFLAGS", rustflags);
         }
 
+        let output = cmd.output()?;
+        let (builds, mut other_diagnostics) =
+            parse_build_messages(String::from_utf8_lossy(&output.stdout).lines());
+
+        // cargo reports failures that are not compiler diagnostics, e.g. an invalid manifest,
+        // on stderr only. Surface them if a day failed without an explanation.
+        let is_unexplained = |day: &Day| {
+            builds
+                .get(day)
+                .is_none_or(|b| b.executable.is_none() && b.diagnostics.is_empty())
+        };
+
+        if other_diagnostics.is_empty() && days.iter().any(is_unexplained) {
+            other_diagnostics.push(String::from_utf8_lossy(&output.stderr).into_owned());
+        }
+
+        Ok((builds, other_diagnostics))
+    }
+
+    /// Look up a key of a JSON object.
+    fn json_field<'a>(value: &'a JsonValue, key: &str) -> Option<&'a JsonValue> {
+        value.get::<HashMap<String, JsonValue>>()?.get(key)
+    }
+
+    fn json_str<'a>(value: &'a JsonValue, key: &str) -> Option<&'a str> {
+        json_field(value, key)?.get::<String>().map(String::as_str)
+    }
+
+    /// The day of the bin target a cargo message refers to, if any. Bins are named after their
+    /// year and day, e.g. `2023_01`, or only after their day in trees that predate years, e.g. `01`.
+    fn json_target_day(message: &JsonValue) -> Option<Day> {
+        let target = json_field(message, "target")?;
+
+        let is_bin = json_field(target, "kind")?
+            .get::<Vec<JsonValue>>()?
+            .iter()
+            .any(|kind| kind.get::<String>().is_some_and(|kind| kind == "bin"));
+
+        if is_bin {
+            let name = json_str(target, "name")?;
+            let day = name.split_once('_').map_or(name, |(_, day)| day);
+            day.parse().ok()
+        } else {
+            None
+        }
+    }
+
+    /// Parse the JSON messages emitted by `cargo build --message-format=json`.
+    pub fn parse_build_messages<'a>(
+        lines: impl Iterator<Item = &'a str>,
+    ) -> (HashMap<Day, Build>, Vec<String>) {
+        let mut builds: HashMap<Day, Build> = HashMap::new();
+        let mut other_diagnostics = vec![];
+
+        for message in lines.filter_map(|line| line.parse::<JsonValue>().ok()) {
+            let day = json_target_day(&message);
+
+            match json_str(&message, "reason") {
+                Some("compiler-artifact") => {
+                    if let (Some(day), Some(executable)) = (day, json_str(&message, "executable")) {
+                        builds.entry(day).or_default().executable = Some(executable.into());
+                    }
+                }
+                Some("compiler-message") => {
+                    let Some(rendered) =
+                        json_field(&message, "message").and_then(|m| json_str(m, "rendered"))
+                    else {
+                        continue;
+                    };
+
+                    match day {
+                        Some(day) => builds
+                            .entry(day)
+                            .or_default()
+                            .diagnostics
+                            .push(rendered.into()),
+                        None => other_diagnostics.push(rendered.into()),
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        (builds, other_diagnostics)
+    }
+
+    /// Run the compiled solution bin of a day.
+    pub fn run_solution(
+        executable: &Path,
+        is_timed: bool,
+        scheduling: &SchedulingOptions,
+    ) -> Result<SolutionOutput, Error> {
+        let mut args: Vec<String> = vec![];
+
         if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+            // mirror `--time` flag and scheduling options to child invocations.
+            args.push("--time".into());
+            args.extend(scheduling.to_args());
         }
 
         // spawn child command with piped stdout/stderr.
         // forward output to stdout/stderr while grabbing stdout lines.
 
-        let mut cmd = Command::new("cargo")
+        let mut cmd = Command::new(executable);
+        output::configure_child(&mut cmd, !output::is_quiet());
+
+        let mut cmd = cmd
             .args(&args)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
@@ -115,24 +347,119 @@ pub mod child_commands {
             });
         });
 
+        let quiet = output::is_quiet();
+
         for line in stdout.lines() {
             let line = line.unwrap();
-            println!("{line}");
+            if !quiet {
+                println!("{line}");
+            }
             output.push(line);
         }
 
         thread.join().unwrap();
-        cmd.wait()?;
+        let status = cmd.wait()?;
+
+        Ok(SolutionOutput {
+            lines: output,
+            success: status.success(),
+        })
+    }
 
-        Ok(output)
+    /// Remove ANSI escape sequences such as [`crate::template::ANSI_BOLD`] from a line.
+    fn strip_ansi(line: &str) -> String {
+        let mut stripped = String::with_capacity(line.len());
+        let mut chars = line.chars();
+
+        while let Some(c) = chars.next() {
+            if c == '\x1b' {
+                // skip until the end of the escape sequence, e.g. `\x1b[1m`.
+                for c in chars.by_ref() {
+                    if c.is_ascii_alphabetic() {
+                        break;
+                    }
+                }
+            } else {
+                stripped.push(c);
+            }
+        }
+
+        stripped
+    }
+
+    /// A part as printed by a solution.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct ParsedPart {
+        pub part: u8,
+        /// `None` if the solution did not return a result for this part (`✖`).
+        pub answer: Option<String>,
+        /// Formatted execution time, e.g. `74.13ns`.
+        pub time: Option<String>,
+    }
+
+    /// Parse the time of a line like `Part 1: 42 (74.13ns @ 100000 samples)`.
+    fn parse_line_time(line: &str) -> Option<String> {
+        let (_, time) = line.rsplit_once(" (")?;
+        let time = time.split(['@', ')']).next()?.trim();
+        parse_duration(time).map(|_| time.to_string())
+    }
+
+    /// Parse the parts printed by a solution, in order of appearance.
+    pub fn parse_parts(output: &[String]) -> Vec<ParsedPart> {
+        // a line might contain an intermediate result, followed by `\r` and the final result.
+        let mut lines = output
+            .iter()
+            .map(|l| strip_ansi(l.rsplit('\r').next().unwrap_or_default()))
+            .peekable();
+
+        let mut parts = vec![];
+
+        while let Some(line) = lines.next() {
+            let Some((part, rest)) = line
+                .strip_prefix("Part ")
+                .and_then(|rest| rest.split_once(": "))
+            else {
+                continue;
+            };
+
+            let Ok(part) = part.parse::<u8>() else {
+                continue;
+            };
+
+            let rest = rest
+                .rsplit_once(" [")
+                .filter(|(_, scheduling)| scheduling.ends_with(']'))
+                .map_or(rest, |(rest, _)| rest);
+
+            let time = parse_line_time(rest);
+
+            let answer = if rest.starts_with('✖') {
+                None
+            } else if rest.starts_with('▼') {
+                // multi-line answers are printed below the line with the timing.
+                let mut answer_lines = vec![];
+                while let Some(l) =
+                    lines.next_if(|l| !l.starts_with("Part ") && !l.starts_with(BREAKDOWN_PREFIX))
+                {
+                    answer_lines.push(l);
+                }
+                Some(answer_lines.join("\n").trim_end().to_string())
+            } else {
+                Some(rest.rsplit_once(" (").map_or(rest, |(rest, _)| rest).into())
+            };
+
+            parts.push(ParsedPart { part, answer, time });
+        }
+
+        parts
     }
 
     pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
         let mut timings = super::Timing {
             day,
-            part_1: None,
-            part_2: None,
+            parts: vec![],
             total_nanos: 0_f64,
+            scheduling: None,
         };
 
         output
@@ -147,16 +474,24 @@ pub mod child_commands {
                     return None;
                 };
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
+                let part = l.split(':').next()?.rsplit("Part ").next()?;
+                let part = usize::from(part.trim().parse::<u8>().ok()?);
+                Some((part, timing_str, nanos, parse_scheduling(l)))
             })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+            .for_each(|(part, timing_str, nanos, scheduling)| {
+                if scheduling.is_some() {
+                    timings.scheduling = scheduling.map(Into::into);
+                }
+
+                if part == 0 {
+                    return;
                 }
 
+                if timings.parts.len() < part {
+                    timings.parts.resize(part, None);
+                }
+                timings.parts[part - 1] = Some(timing_str.into());
+
                 timings.total_nanos += nanos;
             });
 
@@ -167,25 +502,34 @@ pub mod child_commands {
         s.split(postfix).next()?.parse().ok()
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
+    /// Parse a formatted [`std::time::Duration`] like `74.13µs` into nanoseconds.
+    pub fn parse_duration(s: &str) -> Option<f64> {
         // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+        match s {
+            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
+            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
+            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
+            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
+        }
+    }
+
+    /// Parse the scheduling settings appended to a benchmark line, e.g. `[cpu 3, nice -20]`.
+    fn parse_scheduling(line: &str) -> Option<&str> {
+        let (_, suffix) = line.rsplit_once(" samples)")?;
+        suffix.trim().strip_prefix('[')?.strip_suffix(']')
+    }
+
+    fn parse_time(line: &str) -> Option<(&str, f64)> {
         let str_timing = line
             .split(" samples)")
             .next()?
             .split('(')
-            .last()?
+            .next_back()?
             .split('@')
             .next()?
             .trim();
 
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+        Some((str_timing, parse_duration(str_timing)?))
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -204,7 +548,7 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::{parse_build_messages, parse_exec_time, parse_parts, ParsedPart};
 
         use crate::day;
 
@@ -219,8 +563,8 @@ pub mod child_commands {
                 day!(1),
             );
             assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_eq!(res.part(1).unwrap(), "74.13ns");
+            assert_eq!(res.part(2).unwrap(), "74.13ms");
         }
 
         #[test]
@@ -234,8 +578,39 @@ pub mod child_commands {
                 day!(1),
             );
             assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_eq!(res.part(1).unwrap(), "2s");
+            assert_eq!(res.part(2).unwrap(), "100ms");
+        }
+
+        #[test]
+        fn parses_scheduling_settings() {
+            let res = parse_exec_time(
+                &[
+                    "Part 1: 0 (74.13ns @ 100000 samples) [cpu 3, nice -20]".into(),
+                    "Part 2: 10 (74.13ms @ 99999 samples) [cpu 3, nice -20]".into(),
+                    "".into(),
+                ],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
+            assert_eq!(res.part(1).unwrap(), "74.13ns");
+            assert_eq!(res.scheduling.unwrap(), "cpu 3, nice -20");
+        }
+
+        #[test]
+        fn parses_third_parts() {
+            let res = parse_exec_time(
+                &[
+                    "Part 1: 0 (1ms @ 10 samples)".into(),
+                    "Part 2: ✖        ".into(),
+                    "Part 3: 10 (2ms @ 10 samples)".into(),
+                    "".into(),
+                ],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos, 3000000_f64);
+            assert_eq!(res.part(2), None);
+            assert_eq!(res.part(3).unwrap(), "2ms");
         }
 
         #[test]
@@ -249,8 +624,101 @@ pub mod child_commands {
                 day!(1),
             );
             assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
+            assert_eq!(res.part(1).is_none(), true);
+            assert_eq!(res.part(2).is_none(), true);
+            assert_eq!(res.scheduling.is_none(), true);
+        }
+
+        #[test]
+        fn parses_parts() {
+            let res = parse_parts(&[
+                "Part 1: \x1b[1m42\x1b[0m > \x1b[3mbenching\x1b[0m\rPart 1: \x1b[1m42\x1b[0m (74.13ns @ 100000 samples) [cpu 3]".into(),
+                "Part 2: \x1b[1m1 (2)\x1b[0m (1.0ms)".into(),
+                "".into(),
+            ]);
+            assert_eq!(
+                res,
+                vec![
+                    ParsedPart {
+                        part: 1,
+                        answer: Some("42".into()),
+                        time: Some("74.13ns".into())
+                    },
+                    ParsedPart {
+                        part: 2,
+                        answer: Some("1 (2)".into()),
+                        time: Some("1.0ms".into())
+                    }
+                ]
+            );
+        }
+
+        #[test]
+        fn parses_missing_and_multiline_parts() {
+            let res = parse_parts(&[
+                "Part 1: ✖             ".into(),
+                "Part 2: ▼  (1.0ms)".into(),
+                "#..".into(),
+                ".##".into(),
+                "".into(),
+            ]);
+            assert_eq!(
+                res,
+                vec![
+                    ParsedPart {
+                        part: 1,
+                        answer: None,
+                        time: None
+                    },
+                    ParsedPart {
+                        part: 2,
+                        answer: Some("#..\n.##".into()),
+                        time: Some("1.0ms".into())
+                    }
+                ]
+            );
+        }
+
+        #[test]
+        fn parses_build_messages() {
+            let messages = [
+                r#"{"reason":"compiler-artifact","target":{"kind":["lib"],"name":"advent_of_code"},"executable":null}"#,
+                r#"{"reason":"compiler-message","target":{"kind":["lib"],"name":"advent_of_code"},"message":{"rendered":"warning: lib\n"}}"#,
+                r#"{"reason":"compiler-message","target":{"kind":["bin"],"name":"2023_01"},"message":{"rendered":"warning: unused\n"}}"#,
+                r#"{"reason":"compiler-artifact","target":{"kind":["bin"],"name":"2023_01"},"executable":"/target/debug/2023_01"}"#,
+                r#"{"reason":"compiler-message","target":{"kind":["bin"],"name":"2023_02"},"message":{"rendered":"error: oops\n"}}"#,
+                r#"{"reason":"build-finished","success":false}"#,
+                "not json",
+            ];
+
+            let (builds, other) = parse_build_messages(messages.into_iter());
+            assert_eq!(other, vec!["warning: lib\n".to_string()]);
+
+            let day_1 = &builds[&day!(1)];
+            assert_eq!(
+                day_1.executable.as_deref(),
+                Some("/target/debug/2023_01".as_ref())
+            );
+            assert_eq!(day_1.diagnostics, vec!["warning: unused\n".to_string()]);
+
+            let day_2 = &builds[&day!(2)];
+            assert!(day_2.executable.is_none());
+            assert_eq!(day_2.diagnostics, vec!["error: oops\n".to_string()]);
+        }
+
+        #[test]
+        fn parses_build_messages_of_legacy_bins() {
+            let messages = [
+                r#"{"reason":"compiler-artifact","target":{"kind":["bin"],"name":"01"},"executable":"/target/release/01"}"#,
+                r#"{"reason":"compiler-artifact","target":{"kind":["bin"],"name":"advent_of_code"},"executable":"/target/release/advent_of_code"}"#,
+            ];
+
+            let (builds, _) = parse_build_messages(messages.into_iter());
+            assert_eq!(builds.len(), 1);
+            assert_eq!(
+                builds[&day!(1)].executable.as_deref(),
+                Some("/target/release/01".as_ref())
+            );
         }
     }
 }
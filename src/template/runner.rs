@@ -2,34 +2,180 @@
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{stdout, Write};
+use std::path::Path;
 use std::process::Output;
 use std::time::{Duration, Instant};
 use std::{cmp, env, process};
 
-use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::template::examples::{check_part, ExampleCheck, ExamplesManifest};
+use crate::template::output::{self, bold, italic};
+use crate::template::{
+    aoc_cli, current_year, input_hashes, prepare_input, puzzle, sanity_warnings, scheduling,
+    try_read_file, try_read_input, Day,
+};
+
+/// Environment variable that enables input normalization for all solutions run by a command.
+const NORMALIZE_ENV: &str = "AOC_NORMALIZE";
+
+/// Normalize inputs of all solutions that are run by this process, see [`read_input`].
+/// Needs to be called before any other threads are spawned.
+pub fn enable_normalization() {
+    env::set_var(NORMALIZE_ENV, "1");
+}
+
+/// Prefix of the timing breakdown printed below a result in verbose mode.
+pub const BREAKDOWN_PREFIX: &str = "  ↳ ";
+
+/// Timing of a solution part, either of a single run or averaged over a benchmark.
+struct Measurement {
+    duration: Duration,
+    samples: u128,
+    /// Scheduling settings applied to the benchmark thread, if any.
+    scheduling: Option<&'static str>,
+    /// Durations of the individual benchmark samples.
+    timers: Vec<Duration>,
+}
+
+/// Read and check the puzzle input of a day, exits with an explanation if it is not usable.
+///
+/// With `--normalize`, line endings and trailing whitespace are normalized first. Inputs that look
+/// truncated are reported as warnings, as are inputs that changed since the last run.
+pub fn read_input(day: Day) -> String {
+    let (input, raw) = match try_read_input(day, is_normalization_enabled()) {
+        Ok(res) => res,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    for warning in sanity_warnings(&raw) {
+        eprintln!("Warning: {warning}");
+    }
+
+    if let Some(previous) = input_hashes::record(day, &raw) {
+        eprintln!(
+            "Warning: input of day {day} changed since it was last solved (hash {previous} -> {}).",
+            input_hashes::hash_input(&raw)
+        );
+    }
+
+    input
+}
+
+/// Check the puzzle input of a day that was embedded at compile time, see [`read_input`].
+/// `path` is the location the input was embedded from.
+///
+/// Embedded inputs are not hashed, binaries built this way should not depend on the data folder.
+pub fn read_embedded_input(day: Day, path: &str, raw: &str) -> String {
+    match prepare_input(raw.into(), Path::new(path), day, is_normalization_enabled()) {
+        Ok(input) => {
+            for warning in sanity_warnings(raw) {
+                eprintln!("Warning: {warning}");
+            }
+            input
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            eprintln!("The input was embedded at compile time, fix it and rebuild the binary.");
+            process::exit(1);
+        }
+    }
+}
+
+/// Whether inputs are normalized, either by `--normalize` or by a parent process, see [`enable_normalization`].
+pub fn is_normalization_enabled() -> bool {
+    env::args().any(|x| x == "--normalize") || env::var_os(NORMALIZE_ENV).is_some()
+}
 
 pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (result, measurement) =
+        run_timed(func, input, |result| print_result(result, &part_str, None));
+
+    print_result(&result, &part_str, Some(&measurement));
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    if output::is_verbose() {
+        if let Some(breakdown) = format_breakdown(&measurement.timers) {
+            println!("{BREAKDOWN_PREFIX}{breakdown}");
+        }
+    }
 
     if let Some(result) = result {
-        submit_result(result, day, part);
+        if let Some(Ok(output)) = submit_result(result, day, part) {
+            if part == 1 && aoc_cli::is_correct_answer(&output) {
+                show_part_two(day);
+            }
+        }
+    }
+}
+
+/// Refresh the puzzle description of a day after part one was solved and print the newly
+/// unlocked description of part two. The input is not downloaded again.
+fn show_part_two(day: Day) {
+    println!("Downloading the description of part two...");
+
+    if let Err(e) = aoc_cli::download_puzzle(day) {
+        eprintln!("failed to call aoc-cli: {e}");
+        return;
+    }
+
+    match puzzle::read_puzzle(day)
+        .ok()
+        .as_deref()
+        .and_then(puzzle::part_two_section)
+    {
+        Some(section) => {
+            println!();
+            println!("{section}");
+        }
+        None => eprintln!(
+            "\"{}\" does not contain part two yet.",
+            puzzle::puzzle_path(day).display()
+        ),
     }
 }
 
+/// Run a part against the examples of a day listed in `data/<year>/examples.json` and compare the
+/// answers to the expected ones. Without listed examples, the part is run on
+/// `data/<year>/examples/<day>.txt` and its answer is printed unverified.
+///
+/// Returns `false` if any example failed.
+pub fn run_examples<T: Display>(func: impl Fn(&str) -> Option<T>, day: Day, part: u8) -> bool {
+    let solve = |input: &str| func(input).map(|answer| answer.to_string());
+    let manifest = ExamplesManifest::read_from_file();
+    let checks = check_part(&manifest, current_year(), day, part, solve);
+
+    if checks.is_empty() {
+        return match try_read_file("examples", day) {
+            Ok(input) => {
+                match solve(&input) {
+                    Some(answer) => println!("Part {part} ({day}): {} (unverified)", bold(answer)),
+                    None => println!("Part {part} ({day}): ✖"),
+                }
+                true
+            }
+            Err(e) => {
+                eprintln!("Part {part}: {e}");
+                false
+            }
+        };
+    }
+
+    for check in &checks {
+        println!("{check}");
+    }
+
+    checks.iter().all(ExampleCheck::is_passed)
+}
+
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
-fn run_timed<I: Clone, T>(
-    func: impl Fn(I) -> T,
-    input: I,
-    hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+///
+/// The `hook` is called with the result of the first run, before benching starts.
+fn run_timed<I: Clone, T>(func: impl Fn(I) -> T, input: I, hook: impl Fn(&T)) -> (T, Measurement) {
     let timer = Instant::now();
     let result = {
         let input = input.clone();
@@ -43,28 +189,28 @@ fn run_timed<I: Clone, T>(
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
+    let measurement = if std::env::args().any(|x| x == "--time") {
         bench(func, input, &base_time)
     } else {
-        (base_time, 1)
+        Measurement {
+            duration: base_time,
+            samples: 1,
+            scheduling: None,
+            timers: vec![],
+        }
     };
 
-    (result, run.0, run.1)
+    (result, measurement)
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> Measurement {
+    // pin and prioritize the benchmark thread if requested via `--pin` / `--high-priority`.
+    let scheduling = scheduling::apply_from_args();
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+    print_progress(&format!(" > {}", italic("benching")));
 
-    let bench_iterations = cmp::min(
-        10000,
-        cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
-        ),
-    );
+    let bench_iterations =
+        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -76,11 +222,119 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         timers.push(timer.elapsed());
     }
 
-    (
+    Measurement {
+        #[allow(clippy::cast_possible_truncation)]
+        duration: Duration::from_nanos(average_duration(&timers) as u64),
+        samples: bench_iterations,
+        scheduling,
+        timers,
+    }
+}
+
+/// A named implementation of a solution part.
+pub type Variant<I, T> = (&'static str, fn(I) -> Option<T>);
+
+/// Check that all variants of a part agree on the answer, then bench them interleaved so that
+/// every variant is exposed to the same system noise. The first variant is used as the baseline.
+///
+/// Returns `false` if the variants disagree, in which case they are not benched.
+pub fn run_variants<I: Clone, T: Display + PartialEq>(
+    variants: &[Variant<I, T>],
+    input: I,
+    part: u8,
+) -> bool {
+    let width = variants
+        .iter()
+        .map(|(name, _)| name.len())
+        .max()
+        .unwrap_or(0);
+
+    let mut base_times: Vec<Duration> = Vec::with_capacity(variants.len());
+    let mut results: Vec<Option<T>> = Vec::with_capacity(variants.len());
+
+    for (_, func) in variants {
+        let timer = Instant::now();
+        results.push(func(input.clone()));
+        base_times.push(timer.elapsed());
+    }
+
+    if results.iter().any(|result| *result != results[0]) {
+        println!("Part {part}: ✖ variants disagree on the answer");
+        for ((name, _), result) in variants.iter().zip(&results) {
+            match result {
+                Some(result) => println!("  {name:<width$}  {}", bold(result)),
+                None => println!("  {name:<width$}  ✖"),
+            }
+        }
+        return false;
+    }
+
+    match &results[0] {
+        Some(result) => println!(
+            "Part {part}: {} ({} variants agree)",
+            bold(result),
+            variants.len()
+        ),
+        None => println!("Part {part}: ✖ ({} variants agree)", variants.len()),
+    }
+
+    print_progress(&format!(" > {}", italic("benching")));
+
+    let scheduling = scheduling::apply_from_args();
+
+    let average_base_time = base_times.iter().sum::<Duration>() / variants.len() as u32;
+    let rounds = (Duration::from_secs(1).as_nanos() / cmp::max(average_base_time.as_nanos(), 10))
+        .clamp(10, 10000);
+
+    let mut timers: Vec<Vec<Duration>> = vec![vec![]; variants.len()];
+
+    for round in 0..rounds {
+        // rotate the order every round so that no variant always runs first.
         #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+        let offset = round as usize;
+
+        for i in (0..variants.len()).map(|i| (i + offset) % variants.len()) {
+            let cloned = input.clone();
+            let timer = Instant::now();
+            black_box(variants[i].1(black_box(cloned)));
+            timers[i].push(timer.elapsed());
+        }
+    }
+
+    clear_progress();
+    match scheduling {
+        Some(scheduling) => println!("  {rounds} interleaved rounds [{scheduling}]"),
+        None => println!("  {rounds} interleaved rounds"),
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let averages: Vec<f64> = timers
+        .iter()
+        .map(|timers| average_duration(timers) as f64)
+        .collect();
+
+    for (i, ((name, _), average)) in variants.iter().zip(&averages).enumerate() {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let duration = format!("{:.1?}", Duration::from_nanos(*average as u64));
+
+        let relative = if i == 0 {
+            "baseline".to_string()
+        } else if *average <= averages[0] {
+            format!("{:.2}x faster", averages[0] / average.max(1.0))
+        } else {
+            format!("{:.2}x slower", average / averages[0].max(1.0))
+        };
+
+        println!("  {name:<width$}  {duration:>10}  {relative}");
+
+        if output::is_verbose() {
+            if let Some(breakdown) = format_breakdown(&timers[i]) {
+                println!("  {:<width$}  {breakdown}", "");
+            }
+        }
+    }
+
+    true
 }
 
 fn average_duration(numbers: &[Duration]) -> u128 {
@@ -91,45 +345,100 @@ fn average_duration(numbers: &[Duration]) -> u128 {
         / numbers.len() as u128
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
-    } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+fn format_duration(measurement: &Measurement) -> String {
+    let Measurement {
+        duration,
+        samples,
+        scheduling,
+        ..
+    } = measurement;
+
+    match (samples, scheduling) {
+        (1, _) => format!(" ({duration:.1?})"),
+        (_, None) => format!(" ({duration:.1?} @ {samples} samples)"),
+        (_, Some(scheduling)) => format!(" ({duration:.1?} @ {samples} samples) [{scheduling}]"),
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
-    let is_intermediate_result = duration_str.is_empty();
+/// Summarize the spread of benchmark samples, e.g. `min 1.0µs, median 1.2µs, max 3.0µs, σ 0.2µs`.
+fn format_breakdown(timers: &[Duration]) -> Option<String> {
+    if timers.len() < 2 {
+        return None;
+    }
+
+    let mut sorted = timers.to_vec();
+    sorted.sort_unstable();
+
+    #[allow(clippy::cast_precision_loss)]
+    let mean = average_duration(timers) as f64;
+
+    #[allow(clippy::cast_precision_loss)]
+    let variance = timers
+        .iter()
+        .map(|t| (t.as_nanos() as f64 - mean).powi(2))
+        .sum::<f64>()
+        / timers.len() as f64;
+
+    Some(format!(
+        "min {:.1?}, median {:.1?}, max {:.1?}, σ {:.1?}",
+        sorted[0],
+        sorted[sorted.len() / 2],
+        sorted[sorted.len() - 1],
+        Duration::from_secs_f64(variance.sqrt() / 1e9),
+    ))
+}
+
+/// Print a transient status that is overwritten by the next line. Only used in terminals,
+/// redraws via `\r` leave junk in files and CI logs.
+fn print_progress(status: &str) {
+    if output::is_terminal() && !output::is_quiet() {
+        print!("{status}");
+        let _ = stdout().flush();
+    }
+}
+
+/// Return to the start of the line, so that the final result overwrites a transient status.
+fn clear_progress() {
+    if output::is_terminal() && !output::is_quiet() {
+        print!("\r");
+    }
+}
+
+/// Print the result of a part. Without a measurement, the result is printed as a transient
+/// status while the part is benched.
+fn print_result<T: Display>(result: &Option<T>, part: &str, measurement: Option<&Measurement>) {
+    let Some(measurement) = measurement else {
+        match result {
+            Some(result) if result.to_string().contains('\n') => {
+                print_progress(&format!("{part}: ▼ "));
+            }
+            Some(result) => print_progress(&format!("{part}: {}", bold(result))),
+            None => print_progress(&format!("{part}: ✖")),
+        }
+        return;
+    };
+
+    // quiet mode only prints answers.
+    let duration_str = if output::is_quiet() {
+        String::new()
+    } else {
+        format_duration(measurement)
+    };
+
+    clear_progress();
 
     match result {
         Some(result) => {
             if result.to_string().contains('\n') {
-                let str = format!("{part}: ▼ {duration_str}");
-                if is_intermediate_result {
-                    print!("{str}");
-                } else {
-                    print!("\r");
-                    println!("{str}");
-                    println!("{result}");
-                }
+                println!("{part}: ▼ {duration_str}");
+                println!("{result}");
             } else {
-                let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
-                if is_intermediate_result {
-                    print!("{str}");
-                } else {
-                    print!("\r");
-                    println!("{str}");
-                }
+                println!("{part}: {}{duration_str}", bold(result));
             }
         }
         None => {
-            if is_intermediate_result {
-                print!("{part}: ✖");
-            } else {
-                print!("\r");
-                println!("{part}: ✖             ");
-            }
+            // pad to overwrite a transient status.
+            println!("{part}: ✖             ");
         }
     }
 }
@@ -164,8 +473,8 @@ fn submit_result<T: Display>(
         return None;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+    if let Err(e) = aoc_cli::check_unlocked(day).and_then(|()| aoc_cli::check()) {
+        eprintln!("{e}");
         process::exit(1);
     }
 
@@ -0,0 +1,150 @@
+/// Optional CPU pinning and priority control for benchmark runs.
+/// Pinning the benchmark thread to a single core avoids noise from scheduler migrations.
+use std::env;
+use std::sync::OnceLock;
+
+/// How the benchmark thread should be scheduled. By default, the OS scheduler is left alone.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SchedulingOptions {
+    /// Index of the CPU core the benchmark thread gets pinned to.
+    pub cpu: Option<usize>,
+    /// Raise the scheduling priority of the benchmark thread as far as permitted.
+    pub high_priority: bool,
+}
+
+impl SchedulingOptions {
+    /// Read the options from the command-line arguments of a solution binary.
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+
+        let cpu = args
+            .iter()
+            .position(|x| x == "--pin")
+            .and_then(|i| args.get(i + 1))
+            .and_then(|x| x.parse().ok());
+
+        Self {
+            cpu,
+            high_priority: args.iter().any(|x| x == "--high-priority"),
+        }
+    }
+
+    /// Arguments that mirror these options to child invocations.
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![];
+
+        if let Some(cpu) = self.cpu {
+            args.push("--pin".into());
+            args.push(cpu.to_string());
+        }
+
+        if self.high_priority {
+            args.push("--high-priority".into());
+        }
+
+        args
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cpu.is_none() && !self.high_priority
+    }
+}
+
+/// Apply the scheduling options passed to this binary to the current thread.
+/// Options are applied once per process; failures are reported as warnings.
+///
+/// Returns a description of the settings that were applied, e.g. `cpu 3, nice -20`.
+pub fn apply_from_args() -> Option<&'static str> {
+    static APPLIED: OnceLock<Option<String>> = OnceLock::new();
+
+    APPLIED
+        .get_or_init(|| {
+            let options = SchedulingOptions::from_args();
+            if options.is_empty() {
+                None
+            } else {
+                apply(&options)
+            }
+        })
+        .as_deref()
+}
+
+fn apply(options: &SchedulingOptions) -> Option<String> {
+    let mut applied: Vec<String> = vec![];
+
+    if let Some(cpu) = options.cpu {
+        match platform::pin_to_cpu(cpu) {
+            Ok(()) => applied.push(format!("cpu {cpu}")),
+            Err(e) => eprintln!("Warning: could not pin benchmark thread to cpu {cpu}: {e}"),
+        }
+    }
+
+    if options.high_priority {
+        match platform::raise_priority() {
+            Ok(nice) => applied.push(format!("nice {nice}")),
+            Err(e) => eprintln!("Warning: could not raise priority of benchmark thread: {e}"),
+        }
+    }
+
+    if applied.is_empty() {
+        None
+    } else {
+        Some(applied.join(", "))
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use std::{io, mem};
+
+    pub fn pin_to_cpu(cpu: usize) -> Result<(), String> {
+        // SAFETY: `cpu_set_t` is a plain bitmask, the all-zero value is an empty set.
+        let mut set: libc::cpu_set_t = unsafe { mem::zeroed() };
+
+        if cpu >= libc::CPU_SETSIZE as usize {
+            return Err(format!("cpu index exceeds {}", libc::CPU_SETSIZE));
+        }
+
+        // SAFETY: `set` is a valid `cpu_set_t` and `cpu` is within its bounds.
+        // A pid of 0 targets the calling thread.
+        let res = unsafe {
+            libc::CPU_SET(cpu, &mut set);
+            libc::sched_setaffinity(0, mem::size_of::<libc::cpu_set_t>(), &set)
+        };
+
+        if res == 0 {
+            Ok(())
+        } else {
+            Err(io::Error::last_os_error().to_string())
+        }
+    }
+
+    /// Lower the nice value of the calling thread as far as permitted, returns the applied value.
+    pub fn raise_priority() -> Result<i32, String> {
+        let mut last_error = String::new();
+
+        for nice in -20..0 {
+            // SAFETY: plain syscall. On Linux, `who = 0` targets the calling thread.
+            let res = unsafe { libc::setpriority(libc::PRIO_PROCESS, 0, nice) };
+            if res == 0 {
+                return Ok(nice);
+            }
+            last_error = io::Error::last_os_error().to_string();
+        }
+
+        Err(format!(
+            "{last_error}. Raising the priority requires root or the CAP_SYS_NICE capability"
+        ))
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod platform {
+    pub fn pin_to_cpu(_cpu: usize) -> Result<(), String> {
+        Err("cpu pinning is only supported on Linux".into())
+    }
+
+    pub fn raise_priority() -> Result<i32, String> {
+        Err("priority control is only supported on Linux".into())
+    }
+}
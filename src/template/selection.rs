@@ -0,0 +1,219 @@
+/// Selection of days for commands that run more than one day, e.g. `cargo all 1..5`.
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::template::answers::Answers;
+use crate::template::timings::Timings;
+use crate::template::{all_days, Day, DayRange};
+
+/// A single item of a [`DaySpec`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DaySelector {
+    Day(Day),
+    Range(DayRange),
+    All,
+    Odd,
+    Even,
+    /// Days that are neither fully benched nor have a known answer for both parts.
+    Unsolved,
+    /// The `n` days with the highest stored total time.
+    Slowest(usize),
+}
+
+/// A comma-separated list of days, ranges and keywords, e.g. `1..5,7,odd` or `slowest:5`.
+///
+/// Supported items:
+///  - `7`: a single day.
+///  - `1..5`, `..5`, `20..`: a range of days that includes both ends, see [`DayRange`].
+///  - `all`, `odd`, `even`.
+///  - `unsolved`: days that are neither fully benched nor have known answers for both parts.
+///  - `slowest:N`: the `N` days with the highest stored total time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySpec(Vec<DaySelector>);
+
+impl DaySpec {
+    /// A spec that selects every day.
+    pub fn all() -> Self {
+        Self(vec![DaySelector::All])
+    }
+
+    /// Resolve the spec to a set of days, reading stored timings and known answers only if
+    /// the spec contains a keyword that depends on them.
+    pub fn resolve_from_files(&self) -> HashSet<Day> {
+        let needs_stored_data = self
+            .0
+            .iter()
+            .any(|s| matches!(s, DaySelector::Unsolved | DaySelector::Slowest(_)));
+
+        if needs_stored_data {
+            self.resolve(&Timings::read_from_file(), &Answers::read_from_file())
+        } else {
+            self.resolve(&Timings::default(), &Answers::default())
+        }
+    }
+
+    /// Resolve the spec to a set of days.
+    /// Stored timings and known answers are used for the `unsolved` and `slowest:N` keywords.
+    pub fn resolve(&self, timings: &Timings, answers: &Answers) -> HashSet<Day> {
+        let mut days = HashSet::new();
+
+        for selector in &self.0 {
+            match selector {
+                DaySelector::Day(day) => {
+                    days.insert(*day);
+                }
+                DaySelector::Range(range) => days.extend(*range),
+                DaySelector::All => days.extend(all_days()),
+                DaySelector::Odd => days.extend(all_days().filter(|d| d.into_inner() % 2 == 1)),
+                DaySelector::Even => days.extend(all_days().filter(|d| d.into_inner() % 2 == 0)),
+                DaySelector::Unsolved => days.extend(all_days().filter(|day| {
+                    !answers.is_day_complete(*day) && !timings.is_day_complete(*day)
+                })),
+                DaySelector::Slowest(n) => {
+                    let mut slowest: Vec<_> = timings.data.iter().collect();
+                    slowest.sort_unstable_by(|a, b| b.total_nanos.total_cmp(&a.total_nanos));
+                    days.extend(slowest.iter().take(*n).map(|t| t.day));
+                }
+            }
+        }
+
+        days
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for DaySelector {
+    type Err = DaySpecFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || DaySpecFromStrError(s.into());
+
+        match s {
+            "all" => Ok(Self::All),
+            "odd" => Ok(Self::Odd),
+            "even" => Ok(Self::Even),
+            "unsolved" => Ok(Self::Unsolved),
+            s if s.starts_with("slowest:") => {
+                let n = s["slowest:".len()..].parse().map_err(|_| err())?;
+                Ok(Self::Slowest(n))
+            }
+            s if s.contains("..") => s.parse().map(Self::Range).map_err(|_| err()),
+            s => s.parse().map(Self::Day).map_err(|_| err()),
+        }
+    }
+}
+
+impl FromStr for DaySpec {
+    type Err = DaySpecFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .map(|item| item.trim().parse())
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+/// An error which can be returned when parsing a [`DaySpec`].
+#[derive(Debug)]
+pub struct DaySpecFromStrError(String);
+
+impl Error for DaySpecFromStrError {}
+
+impl Display for DaySpecFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid day selection `{}`, expecting a day, a range like `1..5`, `all`, `odd`, `even`, `unsolved` or `slowest:N`",
+            self.0
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::DaySpec;
+    use crate::{
+        day,
+        template::{
+            answers::Answers,
+            timings::{Timing, Timings},
+            Day,
+        },
+    };
+
+    fn resolve(spec: &str, timings: &Timings, answers: &Answers) -> Vec<Day> {
+        let mut days: Vec<Day> = spec
+            .parse::<DaySpec>()
+            .unwrap()
+            .resolve(timings, answers)
+            .into_iter()
+            .collect();
+        days.sort_unstable();
+        days
+    }
+
+    fn get_mock_timings() -> Timings {
+        let timing = |day, total_nanos| Timing {
+            day,
+            parts: vec![Some("1ms".into()), Some("1ms".into())],
+            total_nanos,
+            scheduling: None,
+        };
+
+        Timings {
+            data: vec![
+                timing(day!(1), 1.0),
+                timing(day!(2), 3.0),
+                timing(day!(3), 2.0),
+            ],
+        }
+    }
+
+    #[test]
+    fn parses_lists_and_ranges() {
+        let (timings, answers) = (Timings::default(), Answers::default());
+        assert_eq!(
+            resolve("1,3,7", &timings, &answers),
+            vec![day!(1), day!(3), day!(7)]
+        );
+        assert_eq!(
+            resolve("1..3, 2..=4", &timings, &answers),
+            vec![day!(1), day!(2), day!(3), day!(4)]
+        );
+        assert_eq!(resolve("odd", &timings, &answers).len(), 13);
+        assert_eq!(resolve("even,25", &timings, &answers).len(), 13);
+        assert_eq!(resolve("all", &timings, &answers).len(), 25);
+    }
+
+    #[test]
+    fn resolves_stored_data_keywords() {
+        let timings = get_mock_timings();
+        let mut answers = Answers::default();
+        answers.set(day!(4), 1, "1");
+        answers.set(day!(4), 2, "2");
+
+        assert_eq!(
+            resolve("slowest:2", &timings, &answers),
+            vec![day!(2), day!(3)]
+        );
+
+        let unsolved = resolve("unsolved", &timings, &answers);
+        assert_eq!(unsolved.len(), 21);
+        assert_eq!(unsolved.first(), Some(&day!(5)));
+    }
+
+    #[test]
+    fn rejects_invalid_specs() {
+        assert!("".parse::<DaySpec>().is_err());
+        assert!("1,".parse::<DaySpec>().is_err());
+        assert!("26".parse::<DaySpec>().is_err());
+        assert!("slowest:x".parse::<DaySpec>().is_err());
+        assert!("5..1".parse::<DaySpec>().is_err());
+    }
+}
@@ -1,17 +1,26 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{collections::HashMap, fs, io::Error, path::PathBuf, str::FromStr};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::{current_year, paths, profiles::Profile, Day, PART_NAMES};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+/// Timings of the default profile live in `data/<year>/timings.json`, others next to it.
+fn get_path_for_profile(profile: &Profile) -> PathBuf {
+    if profile.is_default() {
+        paths::data_file("timings.json")
+    } else {
+        paths::data_file(&format!("timings.{profile}.json"))
+    }
+}
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
+    /// Formatted timings of the parts, in order. Parts that were not benched are `None`.
+    pub parts: Vec<Option<String>>,
     pub total_nanos: f64,
+    /// CPU pinning and priority settings that were applied while benching, e.g. `cpu 3, nice -20`.
+    pub scheduling: Option<String>,
 }
 
 /// Represents benchmark times for a set of days.
@@ -21,17 +30,36 @@ pub struct Timings {
     pub data: Vec<Timing>,
 }
 
+impl Timing {
+    /// Formatted timing of a part, if that part was benched.
+    pub fn part(&self, part: u8) -> Option<&str> {
+        let index = usize::from(part).checked_sub(1)?;
+        self.parts.get(index)?.as_deref()
+    }
+}
+
 impl Timings {
     /// Dehydrate timings to a JSON file.
     pub fn store_file(&self) -> Result<(), Error> {
+        self.store_file_for(&Profile::release())
+    }
+
+    /// Dehydrate timings of a build profile to a JSON file.
+    pub fn store_file_for(&self, profile: &Profile) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let mut file = fs::File::create(get_path_for_profile(profile))?;
         json.format_to(&mut file)
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
     pub fn read_from_file() -> Self {
-        let s = fs::read_to_string(TIMINGS_FILE_PATH)
+        Self::read_from_file_for(&Profile::release())
+    }
+
+    /// Rehydrate timings of a build profile from a JSON file.
+    /// If not present, returns empty timings.
+    pub fn read_from_file_for(profile: &Profile) -> Self {
+        let s = fs::read_to_string(get_path_for_profile(profile))
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from);
 
@@ -58,7 +86,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|t| t.day);
         Timings { data }
     }
 
@@ -67,10 +95,12 @@ impl Timings {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
+    /// Whether all parts of a day of the current year were benched.
     pub fn is_day_complete(&self, day: Day) -> bool {
+        let part_count = current_year().part_count();
         self.data
             .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+            .any(|t| t.day == day && (1..=part_count).all(|part| t.part(part).is_some()))
     }
 }
 
@@ -121,24 +151,22 @@ impl From<&Timing> for JsonValue {
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
 
-        let part_1 = value.part_1.clone().map(JsonValue::String);
-        let part_2 = value.part_2.clone().map(JsonValue::String);
+        // the first two parts are always present, files of older versions expect them.
+        for part in 1..=value.parts.len().max(2) {
+            let timing = value.parts.get(part - 1).cloned().flatten();
 
-        map.insert(
-            "part_1".into(),
-            match part_1 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
-        );
+            map.insert(
+                format!("part_{part}"),
+                match timing {
+                    Some(x) => JsonValue::String(x),
+                    None => JsonValue::Null,
+                },
+            );
+        }
 
-        map.insert(
-            "part_2".into(),
-            match part_2 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
-        );
+        if let Some(scheduling) = &value.scheduling {
+            map.insert("scheduling".into(), JsonValue::String(scheduling.clone()));
+        }
 
         JsonValue::Object(map)
     }
@@ -158,26 +186,45 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|day| Day::from_str(day).ok())
             .ok_or("Expected timing.day to be a Day struct.")?;
 
-        let part_1 = json
-            .get("part_1")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_1 to be null or string.")?;
+        let mut parts = vec![];
 
-        let part_2 = json
-            .get("part_2")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_2 to be null or string.")?;
+        for part in 1..=PART_NAMES.len() {
+            let key = format!("part_{part}");
+
+            // parts after the second are optional, events with two parts do not store them.
+            let timing = match json.get(&key) {
+                None if part > 2 => break,
+                None => return Err(format!("Expected timing.{key} to be null or string.")),
+                Some(v) if v.is_null() => None,
+                Some(v) => Some(
+                    v.get::<String>()
+                        .ok_or(format!("Expected timing.{key} to be null or string."))?,
+                ),
+            };
+
+            parts.push(timing.cloned());
+        }
 
         let total_nanos = json
             .get("total_nanos")
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        // optional, timings stored by older versions do not have this key.
+        let scheduling = match json.get("scheduling") {
+            None => None,
+            Some(v) if v.is_null() => None,
+            Some(v) => Some(
+                v.get::<String>()
+                    .ok_or("Expected timing.scheduling to be null or string.")?,
+            ),
+        };
+
         Ok(Timing {
             day,
-            part_1: part_1.cloned(),
-            part_2: part_2.cloned(),
+            parts,
             total_nanos,
+            scheduling: scheduling.cloned(),
         })
     }
 }
@@ -195,21 +242,21 @@ mod tests {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    parts: vec![Some("10ms".into()), Some("20ms".into())],
                     total_nanos: 3e+10,
+                    scheduling: None,
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    parts: vec![Some("30ms".into()), Some("40ms".into())],
                     total_nanos: 7e+10,
+                    scheduling: None,
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: None,
+                    parts: vec![Some("40ms".into()), None],
                     total_nanos: 4e+10,
+                    scheduling: None,
                 },
             ],
         }
@@ -225,9 +272,27 @@ mod tests {
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
             assert_eq!(timing.day, day!(1));
-            assert_eq!(timing.part_1, Some("1ms".to_string()));
-            assert_eq!(timing.part_2, None);
+            assert_eq!(timing.part(1), Some("1ms"));
+            assert_eq!(timing.part(2), None);
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
+            assert_eq!(timing.scheduling, None);
+        }
+
+        #[test]
+        fn handles_third_parts() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "part_3": "3ms", "total_nanos": 4000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.part(2), None);
+            assert_eq!(timing.part(3), Some("3ms"));
+        }
+
+        #[test]
+        fn handles_scheduling_settings() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "scheduling": "cpu 3" }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.scheduling, Some("cpu 3".to_string()));
         }
 
         #[test]
@@ -286,13 +351,13 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: Some("2ms".into()),
+                    parts: vec![Some("1ms".into()), Some("2ms".into())],
                     total_nanos: 3_000_000_000_f64,
+                    scheduling: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -300,13 +365,13 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: None,
+                    parts: vec![Some("1ms".into()), None],
                     total_nanos: 1_000_000_000_f64,
+                    scheduling: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
@@ -314,13 +379,13 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: None,
-                    part_2: None,
+                    parts: vec![None, None],
                     total_nanos: 0.0,
+                    scheduling: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
     }
 
@@ -338,9 +403,9 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(3),
-                    part_1: None,
-                    part_2: None,
+                    parts: vec![None, None],
                     total_nanos: 0_f64,
+                    scheduling: None,
                 }],
             };
             let merged = timings.merge(&other);
@@ -358,9 +423,9 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(2),
-                    part_1: None,
-                    part_2: None,
+                    parts: vec![None, None],
                     total_nanos: 0_f64,
+                    scheduling: None,
                 }],
             };
             let merged = timings.merge(&other);
@@ -0,0 +1,276 @@
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::fs;
+use std::process;
+use std::str::FromStr;
+
+use crate::template::{paths, Event};
+
+#[cfg(feature = "today")]
+use crate::template::unlock_timezone;
+#[cfg(feature = "today")]
+use chrono::{Datelike, Utc};
+
+/// Environment variable that selects the year commands operate on, see [`set_year`].
+pub const YEAR_ENV: &str = "AOC_YEAR";
+
+/// A year of an [`Event`], e.g. Advent of Code 2023. Advent of Code has held an event every
+/// year since 2015.
+///
+/// # Display
+/// Years of Advent of Code display as the plain number, years of other events are prefixed with
+/// the event, see [`Event::prefix`].
+///
+/// ```
+/// # use advent_of_code::template::{Event, Year};
+/// let year: Year = "2023".parse().unwrap();
+/// assert_eq!(year.to_string(), "2023");
+///
+/// let year: Year = "ec2024".parse().unwrap();
+/// assert_eq!(year.event(), Event::EverybodyCodes);
+/// assert_eq!(year.to_string(), "ec2024");
+/// ```
+///
+/// # Ordering
+/// Years are ordered chronologically first, years of different events in the same year are
+/// ordered by event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year {
+    // declared first so that the derived ordering is chronological.
+    year: u16,
+    event: Event,
+}
+
+impl Year {
+    /// Creates a [`Year`] of Advent of Code from the provided value if it's not before the first
+    /// event, returns [`None`] otherwise.
+    pub fn new(year: u16) -> Option<Self> {
+        Self::new_in(Event::AdventOfCode, year)
+    }
+
+    /// Creates a [`Year`] of an event from the provided value if it's not before the first
+    /// edition of the event, returns [`None`] otherwise.
+    pub fn new_in(event: Event, year: u16) -> Option<Self> {
+        if year < event.first_year() {
+            return None;
+        }
+        Some(Self { event, year })
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(event: Event, year: u16) -> Self {
+        Self { event, year }
+    }
+
+    /// Converts the [`Year`] into an [`u16`], dropping its event.
+    pub fn into_inner(self) -> u16 {
+        self.year
+    }
+
+    /// The event the year belongs to.
+    pub const fn event(self) -> Event {
+        self.event
+    }
+
+    /// The number of days (or quests) of the event of this year, see [`Event::day_count`].
+    pub const fn day_count(self) -> u8 {
+        self.event.day_count(self.year)
+    }
+
+    /// The number of parts of the puzzles of this year, see [`Event::part_count`].
+    pub const fn part_count(self) -> u8 {
+        self.event.part_count()
+    }
+
+    /// Name of the library module of the year, e.g. `y2023` or `ec2024`.
+    pub fn module_name(self) -> String {
+        match self.event {
+            Event::AdventOfCode => format!("y{self}"),
+            _ => self.to_string(),
+        }
+    }
+
+    /// Parse the name of a library module of a year, see [`Year::module_name`].
+    pub fn from_module_name(name: &str) -> Option<Self> {
+        match name.strip_prefix('y') {
+            Some(year) => Self::new(year.parse().ok()?),
+            None => name
+                .parse()
+                .ok()
+                .filter(|y: &Year| y.event != Event::AdventOfCode),
+        }
+    }
+
+    /// Human readable name of the year, e.g. `2023` or `Everybody Codes 2024`.
+    pub fn title(self) -> String {
+        match self.event {
+            Event::AdventOfCode => self.year.to_string(),
+            event => format!("{event} {}", self.year),
+        }
+    }
+}
+
+#[cfg(feature = "today")]
+impl Year {
+    /// Returns the current year of Advent of Code, in the timezone puzzles unlock in, see
+    /// [`unlock_timezone`].
+    pub fn today() -> Self {
+        // NOTE: the clock of the machine is well past the first event.
+        let now = Utc::now().with_timezone(&unlock_timezone());
+        Self {
+            event: Event::AdventOfCode,
+            year: u16::try_from(now.year()).unwrap_or(u16::MAX),
+        }
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.event.prefix(), self.year)
+    }
+}
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Advent of Code has no prefix, check it last.
+        let (event, year) = Event::ALL
+            .iter()
+            .rev()
+            .find_map(|event| Some((*event, s.strip_prefix(event.prefix())?)))
+            .ok_or(YearFromStrError)?;
+
+        let year = year.parse().map_err(|_| YearFromStrError)?;
+        Self::new_in(event, year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year of 2015 or later, or a year of Everybody Codes like `ec2024`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The year commands and solutions operate on.
+///
+/// Resolved in order of precedence:
+///  1. the `AOC_YEAR` environment variable, set by `--year` or `.cargo/config.toml`.
+///  2. the `AOC_YEAR` environment variable at compile time.
+///  3. the latest year that has a folder in `data/`.
+///
+/// Exits the process if none of these yield a year.
+pub fn current_year() -> Year {
+    let configured = env::var(YEAR_ENV)
+        .ok()
+        .or_else(|| option_env!("AOC_YEAR").map(String::from));
+
+    if let Some(year) = configured {
+        return year.parse().unwrap_or_else(|e| {
+            eprintln!("invalid `{YEAR_ENV}` \"{year}\": {e}.");
+            process::exit(1);
+        });
+    }
+
+    latest_data_year().unwrap_or_else(|| {
+        eprintln!("No year configured, pass `--year <year>` or set `{YEAR_ENV}`.");
+        process::exit(1);
+    })
+}
+
+/// Select the year for this process and all child processes spawned afterwards.
+/// Needs to be called before any other threads are spawned.
+pub fn set_year(year: Year) {
+    env::set_var(YEAR_ENV, year.to_string());
+}
+
+/// The latest year that has a folder in `data/`, e.g. `data/2023`.
+fn latest_data_year() -> Option<Year> {
+    fs::read_dir(paths::root().join("data"))
+        .ok()?
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| entry.file_name().to_str()?.parse().ok())
+        .max()
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context, e.g. `year!(2023)` for Advent of Code or
+/// `year!(ec 2024)` for Everybody Codes.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {
+        $crate::year!(@event AdventOfCode, $year)
+    };
+    (ec $year:expr) => {
+        $crate::year!(@event EverybodyCodes, $year)
+    };
+    (@event $event:ident, $year:expr) => {{
+        const _ASSERT: () = assert!(
+            $year >= $crate::template::Event::$event.first_year(),
+            concat!("invalid year `", $year, "`, the event did not take place yet"),
+        );
+        $crate::template::Year::__new_unchecked($crate::template::Event::$event, $year)
+    }};
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Year;
+    use crate::template::Event;
+    use crate::year;
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2023".parse::<Year>().unwrap(), year!(2023));
+        assert_eq!("2015".parse::<Year>().unwrap(), year!(2015));
+        assert_eq!("ec2024".parse::<Year>().unwrap(), year!(ec 2024));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("ec2023".parse::<Year>().is_err());
+        assert!("23".parse::<Year>().is_err());
+        assert!("year".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn follows_the_event() {
+        assert_eq!(year!(2024).day_count(), 25);
+        assert_eq!(year!(2025).day_count(), 12);
+        assert_eq!(year!(ec 2024).day_count(), 20);
+        assert_eq!(year!(ec 2024).part_count(), 3);
+        assert_eq!(year!(ec 2024).event(), Event::EverybodyCodes);
+    }
+
+    #[test]
+    fn orders_chronologically() {
+        assert!(year!(ec 2024) < year!(2025));
+        assert!(year!(2023) < year!(ec 2024));
+        assert!(year!(2024) < year!(ec 2024));
+        assert_eq!(
+            [year!(2025), year!(ec 2024), year!(2023)].into_iter().max(),
+            Some(year!(2025))
+        );
+    }
+
+    #[test]
+    fn names_modules() {
+        assert_eq!(year!(2023).module_name(), "y2023");
+        assert_eq!(year!(ec 2024).module_name(), "ec2024");
+        assert_eq!(Year::from_module_name("y2023"), Some(year!(2023)));
+        assert_eq!(Year::from_module_name("ec2024"), Some(year!(ec 2024)));
+        assert_eq!(Year::from_module_name("2023"), None);
+        assert_eq!(Year::from_module_name("mod.rs"), None);
+    }
+}